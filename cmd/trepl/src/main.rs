@@ -17,6 +17,8 @@ struct Indexer {
     idx: Option<trigram_rs::Index>,
     docs: Option<Vec<String>>,
     ids: Option<Vec<trigram_rs::DocID>>,
+    // the strings searched for, highlighted by print
+    terms: Vec<String>,
 }
 
 impl Cmd for Indexer {
@@ -31,6 +33,7 @@ impl Cmd for Indexer {
             "trigrams" => self.run_trigrams(args),
             "prune" => self.run_prune(args),
             "delete" => self.run_delete(args),
+            "explain" => self.run_explain(args),
             _ => Err("unknown command".to_string()),
         }
     }
@@ -54,10 +57,15 @@ impl Indexer {
             }
         }
 
-        let strdocs: Vec<&str> = docs.iter().map(AsRef::as_ref).collect();
-
         let t0 = Instant::now();
-        let idx = trigram_rs::Index::new_with_documents(strdocs);
+        // the stored text lets print locate matches the way queries see them
+        let mut idx = trigram_rs::Index::with_options(trigram_rs::Options {
+            store_documents: true,
+            ..trigram_rs::Options::default()
+        });
+        for d in docs.iter() {
+            idx.add(d);
+        }
 
         println!(
             "indexed {} documents in {}ms",
@@ -68,6 +76,7 @@ impl Indexer {
         self.idx = Some(idx);
         self.docs = Some(docs);
         self.ids = None;
+        self.terms.clear();
 
         Ok(())
     }
@@ -99,6 +108,7 @@ impl Indexer {
             }
             println!("found {} hits in {}ms", found, t0.elapsed().as_millis());
         }
+        self.terms = args.clone();
 
         Ok(())
     }
@@ -124,6 +134,7 @@ impl Indexer {
         let ids = self.idx.as_ref().unwrap().query_trigrams(&trigrams);
         println!("found {} hits in {}ms", ids.len(), t0.elapsed().as_millis());
         self.ids = Some(ids);
+        self.terms = args.clone();
 
         Ok(())
     }
@@ -133,11 +144,23 @@ impl Indexer {
             return Err("no search results".to_string());
         }
 
+        let idx = self.idx.as_ref().unwrap();
         let docs = self.docs.as_ref().unwrap();
         let ids = self.ids.as_ref().unwrap();
 
+        let highlight = trigram_rs::Highlight {
+            open: "\x1b[1;31m".to_string(),
+            close: "\x1b[0m".to_string(),
+            context: 0,
+        };
+
         for id in ids {
-            println!("{}: {}", id.as_usize(), docs[id.as_usize()]);
+            let doc = &docs[id.as_usize()];
+            let mut ranges = Vec::new();
+            for t in self.terms.iter() {
+                ranges.extend(idx.matches(*id, t));
+            }
+            println!("{}: {}", id.as_usize(), highlight.apply(doc, &ranges));
         }
 
         Ok(())
@@ -175,6 +198,7 @@ impl Indexer {
         );
 
         self.ids = Some(ids);
+        self.terms = args.clone();
 
         Ok(())
     }
@@ -213,6 +237,7 @@ impl Indexer {
         );
 
         self.ids = Some(new_ids.to_vec());
+        self.terms.extend(args.iter().cloned());
 
         Ok(())
     }
@@ -293,6 +318,23 @@ impl Indexer {
 
         Ok(())
     }
+
+    pub fn run_explain(&self, args: &Vec<String>) -> Result<(), String> {
+        let idx = match &self.idx {
+            None => return Err("no index loaded".to_string()),
+            Some(idx) => idx,
+        };
+
+        if args.len() == 0 {
+            return Err("missing query".to_string());
+        }
+
+        for q in args.iter() {
+            print!("{}", idx.explain(q));
+        }
+
+        Ok(())
+    }
 }
 
 fn run(prompt: &str, mut commands: impl Cmd) {
@@ -344,6 +386,7 @@ fn main() {
         idx: None,
         docs: None,
         ids: None,
+        terms: Vec::new(),
     };
 
     run("> ", commands);
@@ -0,0 +1,155 @@
+use crate::budget;
+use crate::collector::collect_docs;
+use crate::merge::CountMerge;
+use crate::{Budget, Collector, DocID, Index, QueryError};
+
+/// Principal is a caller of query_as. It sees the documents granted to any
+/// of its groups.
+#[derive(Debug, Clone, Default)]
+pub struct Principal {
+    pub groups: Vec<String>,
+}
+
+impl Principal {
+    pub fn new<S: AsRef<str>>(groups: &[S]) -> Principal {
+        Principal {
+            groups: groups.iter().map(|g| g.as_ref().to_string()).collect(),
+        }
+    }
+}
+
+impl Index {
+    // Grant lets members of group see document id
+    pub fn grant(&mut self, id: DocID, group: &str) {
+        let docs = self.groups.entry(group.to_string()).or_default();
+        if let Err(i) = docs.binary_search(&id) {
+            docs.insert(i, id);
+        }
+    }
+
+    // Revoke stops members of group from seeing document id
+    pub fn revoke(&mut self, id: DocID, group: &str) {
+        if let Some(docs) = self.groups.get_mut(group) {
+            if let Ok(i) = docs.binary_search(&id) {
+                docs.remove(i);
+            }
+        }
+    }
+
+    // Allowed returns the documents principal may see, in DocID order
+    pub fn allowed(&self, principal: &Principal) -> Vec<DocID> {
+        let lists = principal
+            .groups
+            .iter()
+            .filter_map(|g| self.groups.get(g))
+            .map(|l| l.as_slice())
+            .collect();
+        CountMerge::new(lists).map(|(d, _)| d).collect()
+    }
+
+    // QueryAs is like Query but only returns documents principal may see.
+    // The allowed set takes part in the planner's intersections, so results
+    // are trimmed during evaluation and never include hidden documents.
+    pub fn query_as(&self, principal: &Principal, s: &str) -> Vec<DocID> {
+        budget::unlimited(|b| self.query_as_with_budget(principal, s, b))
+    }
+
+    // QueryAsWithBudget is like QueryAs but stops when budget expires
+    pub fn query_as_with_budget(
+        &self,
+        principal: &Principal,
+        s: &str,
+        budget: &Budget,
+    ) -> Result<Vec<DocID>, QueryError> {
+        collect_docs(|docs| self.run_as(principal, s, budget, docs))
+    }
+
+    // QueryAsCollect is like QueryAs but hands the matching documents to
+    // collector, so counts and pages only cover what principal may see
+    pub fn query_as_collect(&self, principal: &Principal, s: &str, collector: &mut dyn Collector) {
+        budget::unlimited(|b| self.run_as(principal, s, b, collector));
+    }
+
+    // RunAs evaluates s restricted to the documents principal may see
+    fn run_as<C: Collector + ?Sized>(
+        &self,
+        principal: &Principal,
+        s: &str,
+        budget: &Budget,
+        collector: &mut C,
+    ) -> Result<(), QueryError> {
+        let allowed = self.allowed(principal);
+        let ts = self.extract_query(s);
+        let seq = self.position_sequence(s);
+        self.run(&ts, Some(&allowed), seq.as_deref(), budget, None, collector)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Budget, Count, DocID, FirstN, Index, Options, Principal, QueryError};
+
+    #[test]
+    fn test_query_as() {
+        let docs = vec![
+            "acme roadmap",
+            "acme payroll",
+            "globex roadmap",
+            "public roadmap",
+        ];
+        let mut idx = Index::new_with_documents(docs);
+
+        idx.grant(DocID(0), "acme");
+        idx.grant(DocID(1), "acme-hr");
+        idx.grant(DocID(2), "globex");
+        for id in 0..4 {
+            idx.grant(DocID::from_i32(id), "admin");
+        }
+        idx.grant(DocID(3), "everyone");
+        idx.grant(DocID(3), "everyone");
+
+        let alice = Principal::new(&["acme", "everyone"]);
+        let bob = Principal::new(&["globex", "everyone"]);
+        let root = Principal::new(&["admin"]);
+        let nobody = Principal::default();
+
+        assert_eq!(idx.allowed(&alice), vec![DocID(0), DocID(3)]);
+        assert_eq!(idx.query_as(&alice, "roadmap"), vec![DocID(0), DocID(3)]);
+        assert_eq!(idx.query_as(&bob, "roadmap"), vec![DocID(2), DocID(3)]);
+        assert_eq!(idx.query_as(&alice, "payroll"), vec![]);
+        assert_eq!(idx.query_as(&root, "payroll"), vec![DocID(1)]);
+        assert_eq!(idx.query_as(&nobody, "roadmap"), vec![]);
+        assert_eq!(idx.query_as(&bob, ""), vec![DocID(2), DocID(3)]);
+
+        let mut count = Count::default();
+        idx.query_as_collect(&root, "roadmap", &mut count);
+        assert_eq!(count.0, 3);
+        let mut first = FirstN::new(1);
+        idx.query_as_collect(&bob, "roadmap", &mut first);
+        assert_eq!(first.docs, vec![DocID(2)]);
+        assert_eq!(
+            idx.query_as_with_budget(&alice, "roadmap", &Budget::unlimited()),
+            Ok(vec![DocID(0), DocID(3)])
+        );
+        let budget = Budget::with_deadline(std::time::Instant::now());
+        match idx.query_as_with_budget(&alice, "roadmap", &budget) {
+            Err(QueryError::Timeout(partial)) => assert!(partial.contains(&DocID(0))),
+            got => panic!("expected timeout, got {:?}", got),
+        }
+
+        idx.revoke(DocID(3), "everyone");
+        assert_eq!(idx.query_as(&alice, "roadmap"), vec![DocID(0)]);
+
+        // positions are checked during the evaluation
+        let mut idx = Index::with_options(Options {
+            positions: true,
+            ..Options::default()
+        });
+        for d in ["abcxbcd", "xxabcdxx", "bcdabc", "abcdabcd"] {
+            let id = idx.add(d);
+            idx.grant(id, "all");
+        }
+        let all = Principal::new(&["all"]);
+        assert_eq!(idx.query_as(&all, "abcd"), vec![DocID(1), DocID(3)]);
+    }
+}
@@ -0,0 +1,301 @@
+use std::borrow::Cow;
+use std::panic::{RefUnwindSafe, UnwindSafe};
+
+use crate::normalize::strip_accents;
+use crate::{
+    extract_all_char_ngrams, extract_all_ngrams, extract_all_padded_char_ngrams,
+    extract_all_padded_ngrams, extract_char_ngrams, extract_ngrams, pad_bytes, pad_chars, CaseFold,
+    Normalization, Unit, T,
+};
+
+/// Analyzer turns documents and queries into trigrams.
+///
+/// Text passes through two stages: normalize maps raw input to the text
+/// that is indexed, and document or query extract trigrams from that
+/// normalized text. Queries that verify candidates compare normalized text.
+/// Like Send and Sync, the unwind safety bounds keep an Index holding the
+/// analyzer usable across catch_unwind.
+pub trait Analyzer: Send + Sync + UnwindSafe + RefUnwindSafe {
+    /// Normalize maps raw text to the form trigrams are extracted from.
+    fn normalize<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        Cow::Borrowed(s)
+    }
+
+    /// Document appends every trigram of the normalized document s to ts,
+    /// duplicates included.
+    fn document(&self, s: &str, ts: &mut Vec<T>);
+
+    /// Query returns the unique trigrams a document must contain for it to
+    /// contain the normalized query s.
+    fn query(&self, s: &str) -> Vec<T> {
+        let mut all = Vec::<T>::new();
+        self.document(s, &mut all);
+
+        let mut ts = Vec::<T>::with_capacity(all.len());
+        for t in all {
+            if !ts.contains(&t) {
+                ts.push(t);
+            }
+        }
+        ts
+    }
+
+    /// Unit is what consecutive trigrams slide over. Queries that reason
+    /// about edits or letter case (query_fuzzy, query_ignore_case) rely on it.
+    fn unit(&self) -> Unit {
+        Unit::Byte
+    }
+
+    /// Anchored returns the unique grams a document must contain to start
+    /// with (start), end with (end), or with both to equal the normalized
+    /// query s. Analyzers that don't index document boundaries return None.
+    fn anchored(&self, _s: &str, _start: bool, _end: bool) -> Option<Vec<T>> {
+        None
+    }
+
+    /// Sequence returns the grams of the normalized query s in order, one
+    /// per position, when the gram at position i of document's output starts
+    /// at unit i of the document. Positional indexes use it to check that a
+    /// query's grams are adjacent and to locate matches; None skips both.
+    fn sequence(&self, _s: &str) -> Option<Vec<T>> {
+        None
+    }
+
+    /// GramLen is the number of units in each gram. Shorter grams are
+    /// auxiliary and don't count towards a document's length.
+    fn gram_len(&self) -> usize {
+        3
+    }
+
+    /// GramsPerEdit bounds how many of the grams query returns a single
+    /// edit can destroy; query_fuzzy relies on it.
+    fn grams_per_edit(&self) -> usize {
+        self.gram_len()
+    }
+}
+
+/// Standard is the built-in analyzer: optional Unicode normalization,
+/// accent stripping and case folding, followed by sliding byte or code
+/// point n-grams, trigrams unless n is changed.
+#[derive(Debug, Clone)]
+pub struct Standard {
+    /// Fold letter case in documents and queries alike.
+    pub case_fold: CaseFold,
+    /// Build trigrams from bytes or from code points.
+    pub unit: Unit,
+    /// Bring documents and queries into a Unicode normalization form.
+    pub normalization: Normalization,
+    /// Remove diacritics from documents and queries, so "cafe" finds "café".
+    pub strip_accents: bool,
+    /// Length of each gram: 2 suits short tokens, 4 or more is more
+    /// selective on large corpora. At most MAX_BYTE_GRAM bytes or
+    /// MAX_CHAR_GRAM code points.
+    pub n: usize,
+    /// Pad documents with n-1 sentinels at each end, so that documents
+    /// shorter than n still have grams and their boundaries are indexed.
+    pub pad: bool,
+    /// Also index every gram shorter than n, so that queries shorter than
+    /// n narrow the candidates instead of matching every document. This
+    /// costs a posting list per distinct unigram and bigram (for n = 3).
+    pub short_grams: bool,
+}
+
+impl Default for Standard {
+    fn default() -> Standard {
+        Standard {
+            case_fold: CaseFold::default(),
+            unit: Unit::default(),
+            normalization: Normalization::default(),
+            strip_accents: false,
+            n: 3,
+            pad: false,
+            short_grams: false,
+        }
+    }
+}
+
+impl Analyzer for Standard {
+    fn normalize<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        let s = if self.strip_accents {
+            strip_accents(s, self.normalization)
+        } else {
+            self.normalization.apply(s)
+        };
+
+        match s {
+            Cow::Borrowed(s) => self.case_fold.apply(s),
+            Cow::Owned(s) => Cow::Owned(self.case_fold.apply(&s).into_owned()),
+        }
+    }
+
+    fn document(&self, s: &str, ts: &mut Vec<T>) {
+        let start = ts.len();
+        match (self.unit, self.pad) {
+            (Unit::Byte, false) => extract_all_ngrams(s, self.n, ts),
+            (Unit::Byte, true) => extract_all_padded_ngrams(s, self.n, ts),
+            (Unit::Char, false) => extract_all_char_ngrams(s, self.n, ts),
+            (Unit::Char, true) => extract_all_padded_char_ngrams(s, self.n, ts),
+        }
+        if self.pad {
+            // move the grams at the start boundary after the others, so the
+            // gram at each position starts at that offset of s
+            ts[start..].rotate_left(self.n - 1);
+        }
+
+        if self.short_grams {
+            for m in 1..self.n {
+                match self.unit {
+                    Unit::Byte => extract_all_ngrams(s, m, ts),
+                    Unit::Char => extract_all_char_ngrams(s, m, ts),
+                }
+            }
+        }
+    }
+
+    fn query(&self, s: &str) -> Vec<T> {
+        // a query is a substring, so it is never padded
+        let ts = match self.unit {
+            Unit::Byte => extract_ngrams(s, self.n),
+            Unit::Char => extract_char_ngrams(s, self.n),
+        };
+        if !ts.is_empty() || !self.short_grams || s.is_empty() {
+            return ts;
+        }
+
+        match self.unit {
+            Unit::Byte => vec![T::from_bytes(s.as_bytes())],
+            Unit::Char => {
+                let chars: Vec<char> = s.chars().collect();
+                vec![T::from_chars(&chars)]
+            }
+        }
+    }
+
+    fn sequence(&self, s: &str) -> Option<Vec<T>> {
+        // boundary and short grams follow all the grams inside s
+        let mut ts = Vec::<T>::new();
+        match self.unit {
+            Unit::Byte => extract_all_ngrams(s, self.n, &mut ts),
+            Unit::Char => extract_all_char_ngrams(s, self.n, &mut ts),
+        }
+        Some(ts)
+    }
+
+    fn anchored(&self, s: &str, start: bool, end: bool) -> Option<Vec<T>> {
+        if !self.pad {
+            return None;
+        }
+
+        let mut ts = Vec::<T>::new();
+        match self.unit {
+            Unit::Byte => {
+                for w in pad_bytes(s, self.n, start, end).windows(self.n) {
+                    ts.push(T::from_bytes(w));
+                }
+            }
+            Unit::Char => {
+                for w in pad_chars(s, self.n, start, end).windows(self.n) {
+                    ts.push(T::from_code_points(w));
+                }
+            }
+        }
+        ts.sort_unstable();
+        ts.dedup();
+        Some(ts)
+    }
+
+    fn unit(&self) -> Unit {
+        self.unit
+    }
+
+    fn gram_len(&self) -> usize {
+        self.n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use crate::{Analyzer, CaseFold, DocID, Index, Options, Standard, T};
+
+    // collapses runs of whitespace and splits camelCase identifiers
+    struct Identifiers(Standard);
+
+    impl Analyzer for Identifiers {
+        fn normalize<'a>(&self, s: &'a str) -> Cow<'a, str> {
+            let mut out = String::with_capacity(s.len());
+            let mut prev = ' ';
+            for c in s.chars() {
+                if c.is_whitespace() || c == '_' {
+                    if prev != ' ' {
+                        out.push(' ');
+                    }
+                    prev = ' ';
+                    continue;
+                }
+                if c.is_uppercase() && prev.is_lowercase() {
+                    out.push(' ');
+                }
+                out.push(c);
+                prev = c;
+            }
+            Cow::Owned(self.0.normalize(&out).into_owned())
+        }
+
+        fn document(&self, s: &str, ts: &mut Vec<T>) {
+            self.0.document(s, ts)
+        }
+    }
+
+    #[test]
+    fn test_analyzer() {
+        let analyzer = Identifiers(Standard {
+            case_fold: CaseFold::Ascii,
+            ..Standard::default()
+        });
+        let mut idx = Index::with_analyzer(analyzer, Options::default());
+
+        idx.add("parseHttpRequest");
+        idx.add("parse_http   response");
+        idx.add("parsehttp");
+
+        assert_eq!(idx.query("parse http"), vec![DocID(0), DocID(1)]);
+        assert_eq!(idx.query("HTTP_REQUEST"), vec![DocID(0)]);
+        assert_eq!(idx.query("parseHttp"), vec![DocID(0), DocID(1)]);
+
+        let got = std::panic::catch_unwind(|| idx.query("request"));
+        assert_eq!(got.ok(), Some(vec![DocID(0)]));
+    }
+
+    #[test]
+    fn test_short_grams() {
+        let analyzer = Standard {
+            pad: true,
+            short_grams: true,
+            ..Standard::default()
+        };
+        let mut idx = Index::with_analyzer(analyzer, Options::default());
+        idx.add("ab");
+        idx.add("abc");
+        idx.add("x");
+        idx.add("");
+
+        let mut ts = Vec::new();
+        idx.analyzer().document("ab", &mut ts);
+        assert_eq!(
+            format!("{:?}", &ts[..4]),
+            "[T(ab\\xff), T(b\\xff\\xff), T(\\xff\\xffa), T(\\xffab)]"
+        );
+
+        assert_eq!(idx.query("ab"), vec![DocID(0), DocID(1)]);
+        assert_eq!(idx.query("bc"), vec![DocID(1)]);
+        assert_eq!(idx.query("x"), vec![DocID(2)]);
+        assert_eq!(idx.query("abc"), vec![DocID(1)]);
+        assert_eq!(idx.query("").len(), 4);
+
+        // without short grams a short query can't narrow anything
+        let idx = Index::new_with_documents(vec!["ab", "abc", "x"]);
+        assert_eq!(idx.query("ab").len(), 3);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{DocID, Index};
+
+impl Index {
+    // QueryPrefix returns the documents that start with s
+    pub fn query_prefix(&self, s: &str) -> Vec<DocID> {
+        self.query_anchored(s, true, false)
+    }
+
+    // QuerySuffix returns the documents that end with s
+    pub fn query_suffix(&self, s: &str) -> Vec<DocID> {
+        self.query_anchored(s, false, true)
+    }
+
+    // QueryExact returns the documents equal to s
+    pub fn query_exact(&self, s: &str) -> Vec<DocID> {
+        self.query_anchored(s, true, true)
+    }
+
+    // QueryAnchored looks up the boundary grams of s when the analyzer pads
+    // documents, and its plain grams otherwise. The grams only show that the
+    // anchored pieces occur somewhere, so candidates are verified against the
+    // stored text; documents without stored text are returned unverified,
+    // unless they were deleted.
+    fn query_anchored(&self, s: &str, start: bool, end: bool) -> Vec<DocID> {
+        let q = self.normalize(s);
+        let candidates = match self.analyzer.anchored(&q, start, end) {
+            Some(ts) => self.query_trigrams(&ts),
+            None => self.query(s),
+        };
+
+        candidates
+            .into_iter()
+            .filter(|&id| {
+                let doc = match self.document(id) {
+                    None => return !self.is_deleted(id),
+                    Some(doc) => self.normalize(doc),
+                };
+                match (start, end) {
+                    (true, true) => doc == q,
+                    (true, false) => doc.starts_with(&*q),
+                    (false, true) => doc.ends_with(&*q),
+                    (false, false) => doc.contains(&*q),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CaseFold, DocID, Index, Options, Standard};
+
+    #[test]
+    fn test_query_anchored() {
+        let docs = vec!["foobar", "barfoo", "foo", "xfoox", "foo bar"];
+        let analyzer = Standard {
+            case_fold: CaseFold::Ascii,
+            pad: true,
+            ..Standard::default()
+        };
+
+        let mut idx = Index::with_analyzer(
+            analyzer.clone(),
+            Options {
+                store_documents: true,
+                ..Options::default()
+            },
+        );
+        for d in docs.iter() {
+            idx.add(d);
+        }
+
+        assert_eq!(idx.query_prefix("foo"), vec![DocID(0), DocID(2), DocID(4)]);
+        assert_eq!(idx.query_prefix("f"), vec![DocID(0), DocID(2), DocID(4)]);
+        assert_eq!(idx.query_suffix("FOO"), vec![DocID(1), DocID(2)]);
+        assert_eq!(idx.query_suffix("ar"), vec![DocID(0), DocID(4)]);
+        assert_eq!(idx.query_exact("foo"), vec![DocID(2)]);
+        assert_eq!(idx.query_exact("fo"), vec![]);
+
+        // the boundary grams alone already rule out most documents
+        let mut idx = Index::with_analyzer(analyzer, Options::default());
+        for d in docs.iter() {
+            idx.add(d);
+        }
+        assert_eq!(idx.query_prefix("foo"), vec![DocID(0), DocID(2), DocID(4)]);
+        assert_eq!(idx.query_exact("foo"), vec![DocID(2)]);
+
+        // without padding only verification anchors the query
+        let idx = Index::new_with_documents(docs.clone());
+        assert_eq!(idx.query_prefix("foo").len(), 5);
+
+        // a query too short for a trigram has every document as candidate,
+        // deleted ones included
+        let mut idx = Index::with_options(Options {
+            store_documents: true,
+            ..Options::default()
+        });
+        for d in docs.iter() {
+            idx.add(d);
+        }
+        idx.delete(docs[0], DocID(0));
+        assert_eq!(idx.query_prefix("f"), vec![DocID(2), DocID(4)]);
+    }
+}
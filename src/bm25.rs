@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use crate::similar::TopK;
+use crate::{DocID, Index, Posting, T};
+
+/// Bm25 holds the parameters of Okapi BM25 ranking
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bm25 {
+    /// how quickly repeated occurrences of a gram stop adding to the score
+    pub k1: f32,
+    /// how much longer documents are penalized, from 0 (not at all) to 1
+    pub b: f32,
+}
+
+impl Default for Bm25 {
+    fn default() -> Bm25 {
+        Bm25 { k1: 1.2, b: 0.75 }
+    }
+}
+
+impl Bm25 {
+    // Idf weighs a gram found in df of n documents
+    fn idf(&self, n: usize, df: usize) -> f32 {
+        let (n, df) = (n as f32, df as f32);
+        (1.0 + (n - df + 0.5) / (df + 0.5)).ln()
+    }
+
+    // Term scores a gram occurring tf times in a document of len grams
+    fn term(&self, idf: f32, tf: u32, len: u32, avg_len: f32) -> f32 {
+        let tf = tf as f32;
+        let norm = 1.0 - self.b + self.b * len as f32 / avg_len;
+        idf * tf * (self.k1 + 1.0) / (tf + self.k1 * norm)
+    }
+}
+
+impl Index {
+    // InsertFrequencies records how often each gram occurs in document id,
+    // before its postings are added
+    pub(crate) fn insert_frequencies(&mut self, ts: &[T], id: DocID) {
+        let mut counts = HashMap::<T, u32>::new();
+        for t in ts {
+            *counts.entry(*t).or_default() += 1;
+        }
+
+        for (t, count) in counts {
+            // keep in step with insert_trigrams, as insert_positions does
+            let again = match self.postings.get(&t) {
+                Some(Posting::Pruned) => continue,
+                Some(Posting::List(l)) => l.last() == Some(&id),
+                None => false,
+            };
+
+            let f = self.frequencies.get_or_default(t);
+            match f.last_mut() {
+                Some(last) if again => *last += count,
+                _ => f.push(count),
+            }
+        }
+    }
+
+    // Rank returns the k documents containing grams of s that score highest
+    // by BM25 with the default parameters, best first
+    pub fn rank(&self, s: &str, k: usize) -> Vec<(DocID, f32)> {
+        self.rank_by(s, k, Bm25::default())
+    }
+
+    // RankBy returns the k documents containing grams of s that score
+    // highest by BM25, best first. A document need not contain every gram.
+    // Without options.term_frequencies each gram counts once per document,
+    // and pruned grams don't contribute.
+    pub fn rank_by(&self, s: &str, k: usize, params: Bm25) -> Vec<(DocID, f32)> {
+        let ts = self.extract_query(s);
+        if ts.is_empty() || k == 0 || self.live == 0 {
+            return Vec::new();
+        }
+
+        // deleted documents stay in the list of all documents, so the
+        // collection is sized by the live count
+        let n = self.live;
+        let avg_len = (self.total_len as f32 / n as f32).max(1.0);
+
+        let mut scores = HashMap::<DocID, f32>::new();
+        for t in ts.iter() {
+            let list = match self.postings.get(t) {
+                Some(Posting::List(l)) => l,
+                _ => continue,
+            };
+
+            let idf = params.idf(n, list.len());
+            let freqs = self.frequencies.get(t);
+            for (i, id) in list.iter().enumerate() {
+                let tf = freqs.and_then(|f| f.get(i)).copied().unwrap_or(1);
+                let len = self.totals.get(id.as_usize()).copied().unwrap_or(0);
+                *scores.entry(*id).or_default() += params.term(idf, tf, len, avg_len);
+            }
+        }
+
+        let mut top = TopK::new(k);
+        for (doc, score) in scores {
+            top.push(doc, score);
+        }
+        top.into_sorted()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bm25, DocID, Index, Options};
+
+    #[test]
+    fn test_rank() {
+        let docs = [
+            "parse parse parse",
+            "parse the input",
+            "print the output",
+            "a longer document that happens to mention parse just once",
+        ];
+
+        let ranked = |idx: &Index, s: &str| -> Vec<DocID> {
+            idx.rank(s, 10).into_iter().map(|(d, _)| d).collect()
+        };
+
+        let mut idx = Index::with_options(Options {
+            term_frequencies: true,
+            ..Options::default()
+        });
+        for d in docs.iter() {
+            idx.add(d);
+        }
+        assert_eq!(ranked(&idx, "parse"), vec![DocID(0), DocID(1), DocID(3)]);
+        assert_eq!(idx.rank("parse", 1).len(), 1);
+        assert_eq!(idx.rank("parse", 0), vec![]);
+        assert_eq!(idx.rank("zzz", 10), vec![]);
+
+        // without length normalization the document length doesn't matter
+        let flat = Bm25 { k1: 1.2, b: 0.0 };
+        let got = idx.rank_by("parse", 10, flat);
+        assert_eq!(got[1].1, got[2].1);
+
+        // each gram counts once, so the shorter document wins
+        let idx = Index::new_with_documents(docs.to_vec());
+        assert_eq!(ranked(&idx, "parse"), vec![DocID(1), DocID(0), DocID(3)]);
+
+        let mut idx = Index::with_options(Options {
+            term_frequencies: true,
+            ..Options::default()
+        });
+        for d in docs.iter() {
+            idx.add(d);
+        }
+        idx.delete("parse parse parse", DocID(0));
+        assert_eq!(ranked(&idx, "parse"), vec![DocID(1), DocID(3)]);
+        assert_eq!(idx.total_len, idx.totals.iter().map(|&n| n as u64).sum());
+        assert_eq!(idx.totals[0], 0);
+        assert_eq!(idx.live, 3);
+
+        // the scores match an index that never held the deleted document
+        let mut fresh = Index::with_options(Options {
+            term_frequencies: true,
+            ..Options::default()
+        });
+        for d in docs[1..].iter() {
+            fresh.add(d);
+        }
+        let got: Vec<f32> = idx.rank("parse", 10).iter().map(|&(_, s)| s).collect();
+        let want: Vec<f32> = fresh.rank("parse", 10).iter().map(|&(_, s)| s).collect();
+        assert_eq!(got, want);
+    }
+}
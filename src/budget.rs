@@ -0,0 +1,142 @@
+use std::error;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::DocID;
+
+/// CancelToken is a shareable flag that aborts queries once it has been set
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Budget bounds the time a query may spend intersecting posting lists
+#[derive(Clone, Debug, Default)]
+pub struct Budget {
+    deadline: Option<Instant>,
+    token: Option<CancelToken>,
+}
+
+impl Budget {
+    // Unlimited returns a budget that never expires
+    pub fn unlimited() -> Budget {
+        Budget::default()
+    }
+
+    pub fn with_deadline(deadline: Instant) -> Budget {
+        Budget {
+            deadline: Some(deadline),
+            token: None,
+        }
+    }
+
+    pub fn with_timeout(timeout: Duration) -> Budget {
+        Budget::with_deadline(Instant::now() + timeout)
+    }
+
+    // CancelOn makes the budget also expire when token is cancelled
+    pub fn cancel_on(mut self, token: CancelToken) -> Budget {
+        self.token = Some(token);
+        self
+    }
+
+    // Check reports whether the query may continue; partial is only
+    // materialized if it may not
+    pub(crate) fn check<F>(&self, partial: F) -> Result<(), QueryError>
+    where
+        F: FnOnce() -> Vec<DocID>,
+    {
+        if let Some(token) = &self.token {
+            if token.is_cancelled() {
+                return Err(QueryError::Cancelled(partial()));
+            }
+        }
+
+        if let Some(deadline) = self.deadline {
+            if Instant::now() >= deadline {
+                return Err(QueryError::Timeout(partial()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Unlimited runs query with a budget that never expires, so it can't fail
+pub(crate) fn unlimited<R, F>(query: F) -> R
+where
+    F: FnOnce(&Budget) -> Result<R, QueryError>,
+{
+    match query(&Budget::unlimited()) {
+        Ok(r) => r,
+        Err(err) => unreachable!("unlimited budget expired: {}", err),
+    }
+}
+
+/// QueryError is returned when a query could not run to completion.
+///
+/// Each variant carries the candidates narrowed down so far; they are a
+/// superset of the full result and still sorted by DocID.
+#[derive(Debug, PartialEq)]
+pub enum QueryError {
+    Timeout(Vec<DocID>),
+    Cancelled(Vec<DocID>),
+}
+
+impl QueryError {
+    pub fn partial(&self) -> &[DocID] {
+        match self {
+            QueryError::Timeout(p) => p,
+            QueryError::Cancelled(p) => p,
+        }
+    }
+
+    pub fn into_partial(self) -> Vec<DocID> {
+        match self {
+            QueryError::Timeout(p) => p,
+            QueryError::Cancelled(p) => p,
+        }
+    }
+
+    // Prepend puts docs, which all come before the partial result, in
+    // front of it
+    pub(crate) fn prepend(self, mut docs: Vec<DocID>) -> QueryError {
+        match self {
+            QueryError::Timeout(p) => {
+                docs.extend(p);
+                QueryError::Timeout(docs)
+            }
+            QueryError::Cancelled(p) => {
+                docs.extend(p);
+                QueryError::Cancelled(docs)
+            }
+        }
+    }
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryError::Timeout(p) => write!(f, "query timed out with {} candidates", p.len()),
+            QueryError::Cancelled(p) => {
+                write!(f, "query cancelled with {} candidates", p.len())
+            }
+        }
+    }
+}
+
+impl error::Error for QueryError {}
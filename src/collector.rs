@@ -0,0 +1,439 @@
+use std::time::{Duration, Instant};
+
+use crate::budget;
+use crate::merge::Cursor;
+use crate::planner::Selection;
+use crate::similar::TopK;
+use crate::{Budget, DocID, Facets, Index, Posting, QueryError, QueryPlan, Step, Verification, T};
+
+/// Collector receives the documents matching a query as they are found,
+/// so callers choose how results are represented and when to stop.
+pub trait Collector {
+    /// Collect is called with each matching document in DocID order.
+    /// Returning false stops the evaluation.
+    fn collect(&mut self, idx: &Index, id: DocID) -> bool;
+}
+
+impl Collector for Vec<DocID> {
+    fn collect(&mut self, _idx: &Index, id: DocID) -> bool {
+        self.push(id);
+        true
+    }
+}
+
+/// Count counts the matching documents
+#[derive(Debug, Clone, Default)]
+pub struct Count(pub usize);
+
+impl Collector for Count {
+    fn collect(&mut self, _idx: &Index, _id: DocID) -> bool {
+        self.0 += 1;
+        true
+    }
+}
+
+/// FirstN keeps the first n matching documents and then stops evaluation
+#[derive(Debug, Clone)]
+pub struct FirstN {
+    n: usize,
+    pub docs: Vec<DocID>,
+}
+
+impl FirstN {
+    pub fn new(n: usize) -> FirstN {
+        FirstN {
+            n,
+            docs: Vec::with_capacity(n),
+        }
+    }
+}
+
+impl Collector for FirstN {
+    fn collect(&mut self, _idx: &Index, id: DocID) -> bool {
+        if self.docs.len() < self.n {
+            self.docs.push(id);
+        }
+        self.docs.len() < self.n
+    }
+}
+
+/// TopScored keeps the k matching documents scored highest by a function
+/// of the document, such as a metadata column
+pub struct TopScored<F: FnMut(&Index, DocID) -> f32> {
+    top: TopK,
+    score: F,
+}
+
+impl<F: FnMut(&Index, DocID) -> f32> TopScored<F> {
+    pub fn new(k: usize, score: F) -> TopScored<F> {
+        TopScored {
+            top: TopK::new(k),
+            score,
+        }
+    }
+
+    // IntoSorted returns the documents best first
+    pub fn into_sorted(self) -> Vec<(DocID, f32)> {
+        self.top.into_sorted()
+    }
+}
+
+impl<F: FnMut(&Index, DocID) -> f32> Collector for TopScored<F> {
+    fn collect(&mut self, idx: &Index, id: DocID) -> bool {
+        let score = (self.score)(idx, id);
+        self.top.push(id, score);
+        true
+    }
+}
+
+/// Bitmap records the matching documents one bit each
+#[derive(Debug, Clone, Default)]
+pub struct Bitmap {
+    words: Vec<u64>,
+}
+
+impl Bitmap {
+    pub fn contains(&self, id: DocID) -> bool {
+        let i = id.as_usize();
+        self.words
+            .get(i / 64)
+            .is_some_and(|w| w & (1 << (i % 64)) != 0)
+    }
+
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+}
+
+impl Collector for Bitmap {
+    fn collect(&mut self, _idx: &Index, id: DocID) -> bool {
+        let i = id.as_usize();
+        if self.words.len() <= i / 64 {
+            self.words.resize(i / 64 + 1, 0);
+        }
+        self.words[i / 64] |= 1 << (i % 64);
+        true
+    }
+}
+
+impl Collector for Facets {
+    fn collect(&mut self, idx: &Index, id: DocID) -> bool {
+        self.add(idx, id);
+        true
+    }
+}
+
+// a posting list to intersect, labelled with its gram; unlabelled lists
+// restrict the candidates some other way, such as to the documents passing
+// a filter
+pub(crate) type List<'a> = (Option<T>, &'a [DocID]);
+
+// how many candidates are evaluated between checks of the budget
+const BUDGET_INTERVAL: usize = 1024;
+
+// CollectDocs runs evaluate, which hands documents to a collector, and
+// returns the documents collected. On expiry the documents already
+// collected are kept in the error's partial result.
+pub(crate) fn collect_docs<F>(evaluate: F) -> Result<Vec<DocID>, QueryError>
+where
+    F: FnOnce(&mut Vec<DocID>) -> Result<(), QueryError>,
+{
+    let mut docs = Vec::<DocID>::new();
+    match evaluate(&mut docs) {
+        Ok(()) => Ok(docs),
+        Err(err) => Err(err.prepend(docs)),
+    }
+}
+
+impl Index {
+    // QueryCollect is like Query but hands the matching documents to
+    // collector instead of returning them
+    pub fn query_collect(&self, s: &str, collector: &mut dyn Collector) {
+        let ts = self.extract_query(s);
+        let seq = self.position_sequence(s);
+        budget::unlimited(|b| self.run(&ts, None, seq.as_deref(), b, None, collector));
+    }
+
+    // QueryTrigramsCollect is like QueryTrigrams but hands the matching
+    // documents to collector
+    pub fn query_trigrams_collect(&self, ts: &[T], collector: &mut dyn Collector) {
+        budget::unlimited(|b| self.run(ts, None, None, b, None, collector));
+    }
+
+    // FilterCollect is like Filter but hands the remaining documents to
+    // collector
+    pub fn filter_collect(&self, docs: &[DocID], ts: &[T], collector: &mut dyn Collector) {
+        if let Some(lists) = self.filter_lists(ts) {
+            budget::unlimited(|b| self.evaluate(docs, lists, None, b, 0, None, collector));
+        }
+    }
+
+    // FilterLists returns the posting lists of ts, skipping pruned ones, or
+    // None if one of them has no list, so nothing can match
+    pub(crate) fn filter_lists(&self, ts: &[T]) -> Option<Vec<List<'_>>> {
+        let mut lists = Vec::<List>::with_capacity(ts.len());
+        for t in ts.iter() {
+            match self.postings.get(t)? {
+                Posting::Pruned => continue,
+                Posting::List(l) => lists.push((Some(*t), l)),
+            }
+        }
+        Some(lists)
+    }
+
+    // Run evaluates the query for trigrams, restricted to the sorted
+    // documents in within if given, and hands the matches to collector;
+    // every query is answered by it. The planner picks the trigrams, and
+    // the shortest of their lists and within supplies the candidates. The
+    // plan, if given, records how the query was evaluated.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn run<C: Collector + ?Sized>(
+        &self,
+        trigrams: &[T],
+        within: Option<&[DocID]>,
+        seq: Option<&[T]>,
+        budget: &Budget,
+        mut plan: Option<&mut QueryPlan>,
+        collector: &mut C,
+    ) -> Result<(), QueryError> {
+        let ts = match self.select_trigrams(trigrams) {
+            Selection::Nothing => return Ok(()),
+            Selection::Everything => Vec::new(),
+            Selection::Trigrams(ts) => ts,
+        };
+
+        // the planner puts the rarest list first
+        let mut lists = Vec::<List>::with_capacity(ts.len() + 1);
+        for t in ts.iter() {
+            if let Some(Posting::List(l)) = self.postings.get(t) {
+                lists.push((Some(*t), l));
+            }
+        }
+
+        let candidates = match within {
+            Some(w) if lists.first().is_none_or(|&(_, l)| w.len() <= l.len()) => w,
+            _ if lists.is_empty() => self.get_all_docs().as_slice(),
+            w => {
+                let (_, first) = lists.remove(0);
+                if let Some(w) = w {
+                    lists.insert(0, (None, w));
+                }
+                first
+            }
+        };
+
+        if let Some(plan) = plan.as_mut() {
+            plan.order = ts;
+        }
+        let threshold = self.planner.verify_threshold;
+        self.evaluate(candidates, lists, seq, budget, threshold, plan, collector)
+    }
+
+    // Evaluate walks candidates one document at a time, handing those that
+    // appear in every list, and contain seq when given, to collector. It
+    // never materializes the intersection, so it stops as soon as collector
+    // has enough. Lists are only consulted while more than threshold
+    // candidates may remain. When budget expires the error holds the
+    // candidates not yet evaluated.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn evaluate<C: Collector + ?Sized>(
+        &self,
+        candidates: &[DocID],
+        mut lists: Vec<List>,
+        seq: Option<&[T]>,
+        budget: &Budget,
+        threshold: usize,
+        mut plan: Option<&mut QueryPlan>,
+        collector: &mut C,
+    ) -> Result<(), QueryError> {
+        // no more documents remain than in the shortest list so far
+        let mut bound = candidates.len();
+        let keep = lists
+            .iter()
+            .take_while(|&&(_, l)| {
+                let more = bound > threshold;
+                bound = bound.min(l.len());
+                more
+            })
+            .count();
+        lists.truncate(keep);
+
+        let mut cursors: Vec<Cursor> = lists.iter().map(|&(_, l)| Cursor::new(l)).collect();
+        let tracing = plan.is_some();
+        let mut steps = vec![(0, 0, Duration::default()); lists.len()];
+        let mut verified = (0, 0, Duration::default());
+
+        // an untraced query skips candidates up to the document a list
+        // moved to; a traced one visits every candidate to count each step
+        let mut cands = Cursor::new(candidates);
+        let mut result = Ok(());
+        let mut k = 0;
+        'docs: while !cands.done() {
+            if k % BUDGET_INTERVAL == 0 {
+                if let Err(err) = budget.check(|| cands.rest().to_vec()) {
+                    result = Err(err);
+                    break;
+                }
+            }
+            k += 1;
+
+            let id = cands.doc();
+            cands.advance();
+
+            for (i, c) in cursors.iter_mut().enumerate() {
+                if !tracing {
+                    c.seek(id);
+                    if c.done() {
+                        break 'docs;
+                    }
+                    if c.doc() != id {
+                        cands.seek(c.doc());
+                        continue 'docs;
+                    }
+                    continue;
+                }
+
+                let t0 = Instant::now();
+                c.seek(id);
+                steps[i].0 += 1;
+                steps[i].2 += t0.elapsed();
+                if c.done() {
+                    break 'docs;
+                }
+                if c.doc() != id {
+                    continue 'docs;
+                }
+                steps[i].1 += 1;
+            }
+
+            if let Some(seq) = seq {
+                let t0 = tracing.then(Instant::now);
+                let contains = self.contains_sequence(id, seq);
+                verified.0 += 1;
+                if let Some(t0) = t0 {
+                    verified.2 += t0.elapsed();
+                }
+                if !contains {
+                    continue;
+                }
+                verified.1 += 1;
+            }
+
+            if !collector.collect(self, id) {
+                break;
+            }
+        }
+
+        if let Some(plan) = plan.as_mut() {
+            for (&(t, _), (candidates, remaining, elapsed)) in lists.iter().zip(steps) {
+                if let Some(trigram) = t {
+                    plan.steps.push(Step {
+                        trigram,
+                        candidates,
+                        remaining,
+                        elapsed,
+                    });
+                }
+            }
+            if seq.is_some() {
+                let (candidates, remaining, elapsed) = verified;
+                plan.verification = Some(Verification {
+                    candidates,
+                    remaining,
+                    elapsed,
+                });
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        extract_trigrams, Bitmap, Collector, Count, DocID, Facets, FirstN, Index, Options,
+        TopScored, Value,
+    };
+
+    // collects the documents whose id is even
+    struct Even(Vec<DocID>);
+
+    impl Collector for Even {
+        fn collect(&mut self, _idx: &Index, id: DocID) -> bool {
+            if id.as_usize().is_multiple_of(2) {
+                self.0.push(id);
+            }
+            true
+        }
+    }
+
+    #[test]
+    fn test_collectors() {
+        let docs = [
+            "foo.rs",
+            "foobar.go",
+            "bar.rs",
+            "foo_test.rs",
+            "food.py",
+            "foo.go",
+        ];
+        let mut idx = Index::with_options(Options {
+            positions: true,
+            ..Options::default()
+        });
+        for (i, d) in docs.iter().enumerate() {
+            let id = idx.add(d);
+            idx.set_metadata(id, "ext", d.rsplit('.').next().unwrap());
+            idx.set_metadata(id, "size", (i * 10) as i64);
+        }
+
+        let mut all = Vec::<DocID>::new();
+        idx.query_collect("foo", &mut all);
+        assert_eq!(all, idx.query("foo"));
+
+        let mut count = Count::default();
+        idx.query_collect("foo", &mut count);
+        assert_eq!(count.0, 5);
+
+        let mut first = FirstN::new(2);
+        idx.query_collect("foo", &mut first);
+        assert_eq!(first.docs, vec![DocID(0), DocID(1)]);
+
+        let mut top = TopScored::new(2, |idx: &Index, id| match idx.metadata(id, "size") {
+            Some(Value::Int(size)) => *size as f32,
+            _ => 0.0,
+        });
+        idx.query_collect("foo", &mut top);
+        assert_eq!(top.into_sorted(), vec![(DocID(5), 50.0), (DocID(4), 40.0)]);
+
+        let mut facets = Facets::new("ext");
+        idx.query_collect("foo", &mut facets);
+        assert_eq!(facets.top(1), vec![(Value::from("go"), 2)]);
+
+        let mut bits = Bitmap::default();
+        idx.query_collect(".rs", &mut bits);
+        assert_eq!(bits.len(), 3);
+        assert!(bits.contains(DocID(3)) && !bits.contains(DocID(1)));
+
+        let mut even = Even(Vec::new());
+        idx.filter_collect(
+            &[DocID(0), DocID(2), DocID(3), DocID(4)],
+            &extract_trigrams("oo"),
+            &mut even,
+        );
+        assert_eq!(even.0.len(), 3);
+        let mut even = Even(Vec::new());
+        idx.filter_collect(
+            &[DocID(0), DocID(2), DocID(3), DocID(4)],
+            &extract_trigrams("foo"),
+            &mut even,
+        );
+        assert_eq!(even.0, vec![DocID(0), DocID(4)]);
+
+        let mut count = Count::default();
+        idx.query_trigrams_collect(&extract_trigrams("zzz"), &mut count);
+        assert_eq!(count.0, 0);
+    }
+}
@@ -0,0 +1,173 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::budget;
+use crate::{Count, Index, Posting, T};
+
+/// PostingStatus describes the posting list of one query trigram
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PostingStatus {
+    Missing,
+    Pruned,
+    Docs(usize),
+}
+
+/// Step is a single posting list intersection performed by a query
+#[derive(Debug, Clone)]
+pub struct Step {
+    pub trigram: T,
+    pub candidates: usize,
+    pub remaining: usize,
+    pub elapsed: Duration,
+}
+
+/// Verification is the check, on an index that records positions, that the
+/// query's grams are adjacent in each remaining candidate
+#[derive(Debug, Clone)]
+pub struct Verification {
+    pub candidates: usize,
+    pub remaining: usize,
+    pub elapsed: Duration,
+}
+
+/// QueryPlan records how a query was evaluated
+#[derive(Debug, Clone)]
+pub struct QueryPlan {
+    pub query: String,
+    /// every trigram extracted from the query
+    pub trigrams: Vec<(T, PostingStatus)>,
+    /// the trigrams chosen by the planner, in intersection order
+    pub order: Vec<T>,
+    pub steps: Vec<Step>,
+    pub verification: Option<Verification>,
+    pub results: usize,
+    pub elapsed: Duration,
+}
+
+impl Index {
+    // Explain runs the query s and reports how it was evaluated
+    pub fn explain(&self, s: &str) -> QueryPlan {
+        let t0 = Instant::now();
+
+        let trigrams = self.extract_query(s);
+        let mut plan = QueryPlan {
+            query: s.to_string(),
+            trigrams: Vec::with_capacity(trigrams.len()),
+            order: Vec::new(),
+            steps: Vec::new(),
+            verification: None,
+            results: 0,
+            elapsed: Duration::default(),
+        };
+
+        for t in trigrams.iter() {
+            let status = match self.postings.get(t) {
+                None => PostingStatus::Missing,
+                Some(Posting::Pruned) => PostingStatus::Pruned,
+                Some(Posting::List(l)) => PostingStatus::Docs(l.len()),
+            };
+            plan.trigrams.push((*t, status));
+        }
+
+        let seq = self.position_sequence(s);
+        let mut count = Count::default();
+        let plan_ref = &mut plan;
+        budget::unlimited(|b| {
+            self.run(
+                &trigrams,
+                None,
+                seq.as_deref(),
+                b,
+                Some(plan_ref),
+                &mut count,
+            )
+        });
+        plan.results = count.0;
+
+        plan.elapsed = t0.elapsed();
+        plan
+    }
+}
+
+impl fmt::Display for QueryPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "query {:?}: {} results in {:?}",
+            self.query, self.results, self.elapsed
+        )?;
+
+        for (t, status) in self.trigrams.iter() {
+            match status {
+                PostingStatus::Missing => writeln!(f, "  {}: missing", t)?,
+                PostingStatus::Pruned => writeln!(f, "  {}: pruned", t)?,
+                PostingStatus::Docs(n) => writeln!(f, "  {}: {} docs", t, n)?,
+            }
+        }
+
+        if let Some((first, _)) = self.order.split_first() {
+            writeln!(f, "  start with {}", first)?;
+        }
+
+        for step in self.steps.iter() {
+            writeln!(
+                f,
+                "  and {}: {} -> {} in {:?}",
+                step.trigram, step.candidates, step.remaining, step.elapsed
+            )?;
+        }
+
+        if let Some(v) = &self.verification {
+            writeln!(
+                f,
+                "  adjacent: {} -> {} in {:?}",
+                v.candidates, v.remaining, v.elapsed
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Index, Options, PostingStatus};
+
+    #[test]
+    fn test_explain() {
+        let docs = vec!["foo", "foobar", "foobfoo", "quxzoot", "zotzot", "azotfoba"];
+        let mut idx = Index::new_with_documents(docs);
+
+        let plan = idx.explain("foob");
+        assert_eq!(plan.trigrams.len(), 2);
+        assert_eq!(plan.trigrams[0].1, PostingStatus::Docs(3));
+        assert_eq!(plan.trigrams[1].1, PostingStatus::Docs(2));
+        assert_eq!(plan.order, vec![plan.trigrams[1].0, plan.trigrams[0].0]);
+        assert_eq!(plan.steps.len(), 1);
+        assert_eq!(plan.steps[0].candidates, 2);
+        assert_eq!(plan.steps[0].remaining, 2);
+        assert_eq!(plan.results, 2);
+
+        assert_eq!(idx.explain("fooz").results, 0);
+
+        idx.prune(0.4);
+        let plan = idx.explain("foob");
+        assert_eq!(plan.trigrams[0].1, PostingStatus::Pruned);
+        assert_eq!(plan.order.len(), 1);
+        assert!(plan.steps.is_empty());
+        assert_eq!(plan.results, 2);
+        assert!(plan.verification.is_none());
+
+        let mut idx = Index::with_options(Options {
+            positions: true,
+            ..Options::default()
+        });
+        for d in ["abcxbcd", "xxabcdxx", "bcdabc", "abcdabcd"] {
+            idx.add(d);
+        }
+        let plan = idx.explain("abcd");
+        let v = plan.verification.unwrap();
+        assert_eq!((v.candidates, v.remaining), (4, 2));
+        assert_eq!(plan.results, idx.query("abcd").len());
+    }
+}
@@ -0,0 +1,119 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::{DocID, Index, Value};
+
+/// Facets counts documents by the value of a metadata column, such as the
+/// language or repository of each result
+#[derive(Debug, Clone)]
+pub struct Facets {
+    column: String,
+    counts: HashMap<Value, usize>,
+    // documents without a value in the column
+    missing: usize,
+}
+
+impl Facets {
+    pub fn new(column: &str) -> Facets {
+        Facets {
+            column: column.to_string(),
+            counts: HashMap::new(),
+            missing: 0,
+        }
+    }
+
+    // Add counts document id of idx
+    pub fn add(&mut self, idx: &Index, id: DocID) {
+        match idx.metadata(id, &self.column) {
+            Some(v) => *self.counts.entry(v.clone()).or_default() += 1,
+            None => self.missing += 1,
+        }
+    }
+
+    // Missing returns how many documents had no value in the column
+    pub fn missing(&self) -> usize {
+        self.missing
+    }
+
+    // Top returns the n values with the most documents, most first; equal
+    // counts are ordered by value, numbers before tags
+    pub fn top(&self, n: usize) -> Vec<(Value, usize)> {
+        let mut buckets: Vec<(Value, usize)> =
+            self.counts.iter().map(|(v, &c)| (v.clone(), c)).collect();
+
+        buckets.sort_by(|(va, ca), (vb, cb)| {
+            cb.cmp(ca).then_with(|| {
+                va.compare(vb).unwrap_or(match va {
+                    Value::Int(_) => Ordering::Less,
+                    Value::Str(_) => Ordering::Greater,
+                })
+            })
+        });
+        buckets.truncate(n);
+        buckets
+    }
+}
+
+impl Index {
+    // Facets counts docs, typically a query result, by the value of column
+    // and returns the top n buckets
+    pub fn facets(&self, docs: &[DocID], column: &str, n: usize) -> Vec<(Value, usize)> {
+        let mut facets = Facets::new(column);
+        for &id in docs {
+            facets.add(self, id);
+        }
+        facets.top(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DocID, Facets, Index, Value};
+
+    #[test]
+    fn test_facets() {
+        let docs = [
+            ("src/main.rs", Some("rust")),
+            ("src/lib.rs", Some("rust")),
+            ("setup.py", Some("python")),
+            ("main.go", Some("go")),
+            ("README", None),
+            ("src/util.rs", Some("rust")),
+            ("main.py", Some("python")),
+        ];
+
+        let mut idx = Index::new();
+        for (path, lang) in docs {
+            let id = idx.add(path);
+            if let Some(lang) = lang {
+                idx.set_metadata(id, "lang", lang);
+            }
+            idx.set_metadata(id, "depth", path.matches('/').count() as i64);
+        }
+
+        let all: Vec<DocID> = (0..docs.len() as i32).map(DocID::from_i32).collect();
+        assert_eq!(
+            idx.facets(&all, "lang", 2),
+            vec![(Value::from("rust"), 3), (Value::from("python"), 2)]
+        );
+        assert_eq!(
+            idx.facets(&idx.query("main"), "lang", 5),
+            vec![
+                (Value::from("go"), 1),
+                (Value::from("python"), 1),
+                (Value::from("rust"), 1)
+            ]
+        );
+        assert_eq!(
+            idx.facets(&idx.query("src/"), "depth", 5),
+            vec![(Value::Int(1), 3)]
+        );
+
+        let mut facets = Facets::new("lang");
+        for &id in all.iter() {
+            facets.add(&idx, id);
+        }
+        assert_eq!(facets.missing(), 1);
+        assert_eq!(facets.top(0), vec![]);
+    }
+}
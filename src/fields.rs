@@ -0,0 +1,300 @@
+use std::collections::HashMap;
+
+use crate::merge::CountMerge;
+use crate::similar::TopK;
+use crate::{intersect2, DocID, Index, Posting, Similarity, T};
+
+// the most distinct field names an index holds; the tag 0 is the whole
+// document
+const MAX_FIELDS: usize = 255;
+
+impl Index {
+    // FieldID returns the tag of the named field, if any document has it
+    fn field_id(&self, name: &str) -> Option<u8> {
+        let i = self.fields.iter().position(|f| f == name)?;
+        Some((i + 1) as u8)
+    }
+
+    fn field_id_or_insert(&mut self, name: &str) -> u8 {
+        if let Some(f) = self.field_id(name) {
+            return f;
+        }
+
+        assert!(self.fields.len() < MAX_FIELDS, "too many fields");
+        self.fields.push(name.to_string());
+        self.field_lengths.push(Vec::new());
+        self.fields.len() as u8
+    }
+
+    // ExtractFields returns the grams of the document made of fields, which
+    // are tagged with the field ids, along with the number of unique grams
+    // in each field
+    fn extract_fields(&self, fields: &[(u8, &str)]) -> (Vec<T>, HashMap<u8, u32>) {
+        let joined: Vec<&str> = fields.iter().map(|&(_, v)| v).collect();
+        let mut ts = Vec::<T>::new();
+        self.extract_document(&joined.join("\n"), &mut ts);
+
+        let n = self.analyzer.gram_len();
+        let mut unique = HashMap::<u8, Vec<T>>::new();
+        for &(f, value) in fields {
+            let start = ts.len();
+            self.extract_document(value, &mut ts);
+            for t in ts[start..].iter_mut() {
+                *t = t.with_field(f);
+                if t.len() >= n {
+                    unique.entry(f).or_default().push(*t);
+                }
+            }
+        }
+
+        let lengths = unique
+            .into_iter()
+            .map(|(f, mut u)| {
+                u.sort_unstable();
+                u.dedup();
+                (f, u.len() as u32)
+            })
+            .collect();
+        (ts, lengths)
+    }
+
+    // AddFields adds a document made of named fields, returning its DocID.
+    // It panics if the index would hold more than 255 distinct field names.
+    pub fn add_fields(&mut self, fields: &[(&str, &str)]) -> DocID {
+        let id = DocID(self.get_all_docs().len() as i32);
+        self.insert_fields(fields, id);
+        id
+    }
+
+    // InsertFields indexes a document made of named fields, such as a title,
+    // path and body. Each field's grams are indexed tagged with the field,
+    // and the fields joined by newlines are indexed as the document's text,
+    // which is what query and the document store see. It panics if the
+    // index would hold more than 255 distinct field names.
+    pub fn insert_fields(&mut self, fields: &[(&str, &str)], id: DocID) {
+        let tagged: Vec<(u8, &str)> = fields
+            .iter()
+            .map(|&(name, value)| (self.field_id_or_insert(name), value))
+            .collect();
+
+        let (ts, lengths) = self.extract_fields(&tagged);
+        for (f, len) in lengths {
+            let l = &mut self.field_lengths[f as usize - 1];
+            if l.len() <= id.as_usize() {
+                l.resize(id.as_usize() + 1, 0);
+            }
+            l[id.as_usize()] = len;
+        }
+
+        self.insert_trigrams(&ts, id);
+
+        let joined: Vec<&str> = fields.iter().map(|&(_, v)| v).collect();
+        self.store_document(&joined.join("\n"), id);
+    }
+
+    // DeleteFields removes a document added with insert_fields
+    pub fn delete_fields(&mut self, fields: &[(&str, &str)], id: DocID) {
+        let tagged: Vec<(u8, &str)> = fields
+            .iter()
+            .filter_map(|&(name, value)| Some((self.field_id(name)?, value)))
+            .collect();
+
+        if let Some(doc) = self.store.get_mut(id.as_usize()) {
+            *doc = None;
+        }
+
+        let (ts, _) = self.extract_fields(&tagged);
+        self.delete_trigrams(&ts, id);
+    }
+
+    // QueryField returns the documents whose field contains s
+    pub fn query_field(&self, field: &str, s: &str) -> Vec<DocID> {
+        let f = match self.field_id(field) {
+            None => return Vec::new(),
+            Some(f) => f,
+        };
+
+        let ts: Vec<T> = self
+            .extract_query(s)
+            .into_iter()
+            .map(|t| t.with_field(f))
+            .collect();
+        self.query_trigrams(&ts)
+    }
+
+    // QueryFields answers queries such as `path:src/ AND body:"unsafe fn"`:
+    // every term must match, either within its field or, without a field,
+    // anywhere in the document. AND between terms is optional.
+    pub fn query_fields(&self, q: &str) -> Vec<DocID> {
+        let mut result: Option<Vec<DocID>> = None;
+
+        for (field, value) in parse_fields(q, |f| self.field_id(f).is_some()) {
+            let docs = match field {
+                Some(f) => self.query_field(f, &value),
+                None => self.query(&value),
+            };
+            match result.as_mut() {
+                None => result = Some(docs),
+                Some(r) => intersect2(r, &docs),
+            }
+            if result.as_ref().is_some_and(|r| r.is_empty()) {
+                break;
+            }
+        }
+
+        result.unwrap_or_else(|| self.copy_all_docs())
+    }
+
+    // SimilarFields returns the k documents most similar to s, scoring each
+    // field by Jaccard similarity and summing the scores times the field's
+    // weight, best first
+    pub fn similar_fields(&self, s: &str, weights: &[(&str, f32)], k: usize) -> Vec<(DocID, f32)> {
+        let ts = self.extract_query(s);
+        if ts.is_empty() || k == 0 {
+            return Vec::new();
+        }
+
+        let mut scores = HashMap::<DocID, f32>::new();
+        for &(name, weight) in weights {
+            let f = match self.field_id(name) {
+                None => continue,
+                Some(f) => f,
+            };
+
+            let mut lists = Vec::with_capacity(ts.len());
+            for t in ts.iter() {
+                if let Some(Posting::List(l)) = self.postings.get(&t.with_field(f)) {
+                    lists.push(l.as_slice());
+                }
+            }
+
+            let lengths = &self.field_lengths[f as usize - 1];
+            for (doc, shared) in CountMerge::new(lists) {
+                let len = lengths.get(doc.as_usize()).copied().unwrap_or(0);
+                let score = Similarity::Jaccard.score(shared, ts.len(), len as usize);
+                *scores.entry(doc).or_default() += weight * score;
+            }
+        }
+
+        let mut top = TopK::new(k);
+        for (doc, score) in scores {
+            top.push(doc, score);
+        }
+        top.into_sorted()
+    }
+}
+
+// ParseFields splits a field query into its terms. A term is `field:value`
+// or a bare value, and double quotes keep spaces inside a value. Only names
+// known is true for are fields, so a value such as `std::io` stays whole.
+fn parse_fields(q: &str, known: impl Fn(&str) -> bool) -> Vec<(Option<&str>, String)> {
+    let mut tokens = Vec::<&str>::new();
+    let mut start = None;
+    let mut quoted = false;
+    for (i, c) in q.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => {
+                if let Some(s) = start.take() {
+                    tokens.push(&q[s..i]);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        if start.is_none() {
+            start = Some(i);
+        }
+    }
+    if let Some(s) = start {
+        tokens.push(&q[s..]);
+    }
+
+    tokens
+        .into_iter()
+        .filter(|&t| t != "AND")
+        .map(|t| match t.split_once(':') {
+            Some((f, v)) if known(f) => (Some(f), v.replace('"', "")),
+            _ => (None, t.replace('"', "")),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_fields;
+    use crate::{DocID, Index};
+
+    #[test]
+    fn test_fields() {
+        assert_eq!(
+            parse_fields(r#"path:src/ AND body:"unsafe fn"  main std::io"#, |f| {
+                f == "path" || f == "body"
+            }),
+            vec![
+                (Some("path"), "src/".to_string()),
+                (Some("body"), "unsafe fn".to_string()),
+                (None, "main".to_string()),
+                (None, "std::io".to_string()),
+            ]
+        );
+
+        let mut idx = Index::new();
+        idx.add_fields(&[
+            ("title", "parser"),
+            ("path", "src/parse.rs"),
+            ("body", "unsafe fn parse() {}"),
+        ]);
+        idx.add_fields(&[
+            ("title", "unsafe code guidelines"),
+            ("path", "docs/unsafe.md"),
+            ("body", "avoid it"),
+        ]);
+        idx.add_fields(&[
+            ("title", "lexer"),
+            ("path", "src/lex.rs"),
+            ("body", "fn lex() {}"),
+        ]);
+
+        assert_eq!(idx.query("unsafe"), vec![DocID(0), DocID(1)]);
+        assert_eq!(idx.query_field("body", "unsafe"), vec![DocID(0)]);
+        assert_eq!(idx.query_field("path", "src/"), vec![DocID(0), DocID(2)]);
+        assert_eq!(idx.query_field("author", "unsafe"), vec![]);
+        assert_eq!(
+            idx.query_fields("path:src/ AND body:unsafe"),
+            vec![DocID(0)]
+        );
+        assert_eq!(idx.query_fields(r#"path:src/ body:"fn l""#), vec![DocID(2)]);
+        assert_eq!(idx.query_fields("unsafe title:code"), vec![DocID(1)]);
+
+        let got = idx.similar_fields("unsafe", &[("title", 2.0), ("body", 1.0)], 3);
+        assert_eq!(
+            got.iter().map(|(d, _)| *d).collect::<Vec<_>>(),
+            vec![DocID(1), DocID(0)]
+        );
+
+        idx.delete_fields(
+            &[
+                ("title", "lexer"),
+                ("path", "src/lex.rs"),
+                ("body", "fn lex() {}"),
+            ],
+            DocID(2),
+        );
+        assert_eq!(idx.query_field("path", "src/"), vec![DocID(0)]);
+
+        // only known field names make a term a field term
+        idx.add_fields(&[("body", "use std::io;")]);
+        assert_eq!(idx.query_fields("std::io"), vec![DocID(3)]);
+        assert_eq!(idx.query_fields("body:std::io"), vec![DocID(3)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "too many fields")]
+    fn test_too_many_fields() {
+        let mut idx = Index::new();
+        for i in 0..256 {
+            idx.add_fields(&[(&format!("f{}", i), "value")]);
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use crate::merge::MinMatch;
+use crate::{DocID, Index, Posting, Unit};
+
+impl Index {
+    // QueryFuzzy returns the documents containing pattern with at most k
+    // edits (insertions, deletions or substitutions of bytes, or of code
+    // points when the index is built from code point trigrams).
+    //
+    // Candidates come from the q-gram lemma: each edit destroys at most n of
+    // the pattern's n-grams, so a match must share all but nk of them (n is
+    // the analyzer's grams_per_edit).
+    // Candidates are verified against the stored text when the index keeps
+    // documents; documents without stored text are returned unverified,
+    // unless they were deleted.
+    pub fn query_fuzzy(&self, pattern: &str, k: usize) -> Vec<DocID> {
+        let ts = self.extract_query(pattern);
+        let mut required = ts.len().saturating_sub(self.analyzer.grams_per_edit() * k);
+
+        let mut lists = Vec::with_capacity(ts.len());
+        for t in ts.iter() {
+            match self.postings.get(t) {
+                None => continue,
+                Some(Posting::Pruned) => required = required.saturating_sub(1),
+                Some(Posting::List(l)) => lists.push(l.as_slice()),
+            }
+        }
+
+        let candidates = if required == 0 {
+            self.copy_all_docs()
+        } else {
+            MinMatch::new(lists, required).map(|(d, _)| d).collect()
+        };
+
+        let pattern = self.normalize(pattern);
+        let pattern_chars: Vec<char> = pattern.chars().collect();
+
+        candidates
+            .into_iter()
+            .filter(|&id| {
+                let doc = match self.document(id) {
+                    None => return !self.is_deleted(id),
+                    Some(doc) => self.normalize(doc),
+                };
+                match self.analyzer.unit() {
+                    Unit::Byte => contains_approx(doc.as_bytes(), pattern.as_bytes(), k),
+                    Unit::Char => {
+                        let chars: Vec<char> = doc.chars().collect();
+                        contains_approx(&chars, &pattern_chars, k)
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+// ContainsApprox reports whether some substring of text is within k edits
+// of pattern. It is Sellers' dynamic program with Ukkonen's cutoff: only
+// the band of rows that can still be within k edits is computed per column.
+pub(crate) fn contains_approx<C: PartialEq>(text: &[C], pattern: &[C], k: usize) -> bool {
+    let m = pattern.len();
+    if m <= k {
+        return true;
+    }
+
+    // col[i] is the distance between pattern[..i] and the best substring of
+    // text ending at the current position; rows past the band only hold values
+    // known to exceed k
+    let mut col: Vec<usize> = (0..=m).collect();
+    let mut last = k;
+
+    for c in text {
+        let top = (last + 1).min(m);
+        let mut diag = 0;
+
+        for i in 1..=top {
+            let cost = if pattern[i - 1] == *c { 0 } else { 1 };
+            let v = (diag + cost).min(col[i - 1] + 1).min(col[i] + 1);
+            diag = col[i];
+            col[i] = v;
+        }
+
+        last = top;
+        while col[last] > k {
+            last -= 1;
+        }
+
+        if last == m {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::contains_approx;
+    use crate::{DocID, Index, Options};
+
+    #[test]
+    fn test_contains_approx() {
+        assert!(contains_approx(b"the quick brown fox", b"quick", 0));
+        assert!(!contains_approx(b"the quack brown fox", b"quick", 0));
+        assert!(contains_approx(b"the quack brown fox", b"quick", 1));
+        assert!(contains_approx(b"the qick brown fox", b"quick", 1));
+        assert!(contains_approx(b"the quiick brown fox", b"quick", 1));
+        assert!(!contains_approx(b"the quaak brown fox", b"quick", 1));
+        assert!(contains_approx(b"", b"ab", 2));
+    }
+
+    #[test]
+    fn test_query_fuzzy() {
+        let docs = vec![
+            "the quick brown fox",
+            "the quack brown fix",
+            "a quick brown dog",
+            "lazy dogs sleep",
+        ];
+
+        let mut idx = Index::with_options(Options {
+            store_documents: true,
+            ..Options::default()
+        });
+        for d in docs.iter() {
+            idx.add(d);
+        }
+
+        assert_eq!(idx.query_fuzzy("quick brown fox", 0), vec![DocID(0)]);
+        assert_eq!(idx.query_fuzzy("quick brown fox", 1), vec![DocID(0)]);
+        assert_eq!(
+            idx.query_fuzzy("quick brown fox", 2),
+            vec![DocID(0), DocID(1), DocID(2)]
+        );
+
+        // a deleted document is not an unverified match
+        idx.delete(docs[1], DocID(1));
+        assert_eq!(
+            idx.query_fuzzy("quick brown fox", 2),
+            vec![DocID(0), DocID(2)]
+        );
+        assert_eq!(
+            idx.query_fuzzy("xyz", 3),
+            vec![DocID(0), DocID(2), DocID(3)]
+        );
+
+        // without the text the candidates can't be verified
+        let mut idx = Index::new_with_documents(docs.clone());
+        assert_eq!(
+            idx.query_fuzzy("quick brown fox", 1),
+            vec![DocID(0), DocID(2)]
+        );
+        idx.delete(docs[2], DocID(2));
+        assert_eq!(idx.query_fuzzy("quick brown fox", 1), vec![DocID(0)]);
+    }
+}
@@ -0,0 +1,242 @@
+use std::ops::Range;
+
+use crate::{DocID, Index, Unit};
+
+/// Highlight marks matches in text and cuts snippets around them
+#[derive(Debug, Clone)]
+pub struct Highlight {
+    /// inserted before each match
+    pub open: String,
+    /// inserted after each match
+    pub close: String,
+    /// bytes of context kept on either side of a match in a snippet
+    pub context: usize,
+}
+
+impl Default for Highlight {
+    fn default() -> Highlight {
+        Highlight {
+            open: "<em>".to_string(),
+            close: "</em>".to_string(),
+            context: 40,
+        }
+    }
+}
+
+// FindAll returns the byte offset of every occurrence of q in doc,
+// overlapping ones included
+pub(crate) fn find_all(doc: &str, q: &str) -> Vec<usize> {
+    let mut starts = Vec::<usize>::new();
+    let mut from = 0;
+    while from <= doc.len() {
+        let at = match doc[from..].find(q) {
+            None => break,
+            Some(i) => from + i,
+        };
+        starts.push(at);
+        from = at + doc[at..].chars().next().map_or(1, char::len_utf8);
+    }
+    starts
+}
+
+// Merge sorts ranges and joins those that overlap or touch
+fn merge(ranges: &[Range<usize>]) -> Vec<Range<usize>> {
+    let mut sorted = ranges.to_vec();
+    sorted.sort_by_key(|r| r.start);
+
+    let mut merged = Vec::<Range<usize>>::with_capacity(sorted.len());
+    for r in sorted {
+        match merged.last_mut() {
+            Some(last) if r.start <= last.end => last.end = last.end.max(r.end),
+            _ => merged.push(r),
+        }
+    }
+    merged
+}
+
+impl Highlight {
+    // Apply wraps every range of text in the markers. Ranges are byte
+    // offsets; overlapping ones are marked once.
+    pub fn apply(&self, text: &str, ranges: &[Range<usize>]) -> String {
+        self.mark(text, 0..text.len(), &merge(ranges))
+    }
+
+    // Snippets returns the stretches of text around each match with the
+    // matches marked; matches whose context overlaps share a snippet
+    pub fn snippets(&self, text: &str, ranges: &[Range<usize>]) -> Vec<String> {
+        let ranges = merge(ranges);
+
+        let mut windows = Vec::<Range<usize>>::new();
+        for r in ranges.iter() {
+            let mut start = r.start.saturating_sub(self.context);
+            while !text.is_char_boundary(start) {
+                start -= 1;
+            }
+            let mut end = (r.end + self.context).min(text.len());
+            while !text.is_char_boundary(end) {
+                end += 1;
+            }
+            windows.push(start..end);
+        }
+
+        merge(&windows)
+            .into_iter()
+            .map(|w| self.mark(text, w, &ranges))
+            .collect()
+    }
+
+    // Mark returns text[window] with the parts inside ranges marked. Ranges
+    // that don't start and end on char boundaries of text are left unmarked.
+    fn mark(&self, text: &str, window: Range<usize>, ranges: &[Range<usize>]) -> String {
+        let mut out = String::with_capacity(window.len());
+        let mut at = window.start;
+        for r in ranges {
+            let (start, end) = (r.start.max(window.start), r.end.min(window.end));
+            if start >= end || !text.is_char_boundary(start) || !text.is_char_boundary(end) {
+                continue;
+            }
+            out.push_str(&text[at..start]);
+            out.push_str(&self.open);
+            out.push_str(&text[start..end]);
+            out.push_str(&self.close);
+            at = end;
+        }
+        out.push_str(&text[at..window.end]);
+        out
+    }
+}
+
+impl Index {
+    // Matches returns where query occurs in document id, as byte ranges of
+    // the document after the analyzer's normalization, which for the
+    // default analyzer is the document itself. The stored text is searched
+    // if there is one, and the positional postings otherwise; a code point
+    // index with only positions reports ranges in code points. Without
+    // either there is nothing to locate matches with, and a pruned gram
+    // can't be located either, so a query with one only matches in stored
+    // text. An empty query matches nowhere.
+    pub fn matches(&self, id: DocID, query: &str) -> Vec<Range<usize>> {
+        let q = self.normalize(query);
+        if q.is_empty() {
+            return Vec::new();
+        }
+
+        if let Some(doc) = self.document(id) {
+            let doc = self.normalize(doc);
+            return find_all(&doc, &q)
+                .into_iter()
+                .map(|at| at..at + q.len())
+                .collect();
+        }
+
+        if !self.options.positions {
+            return Vec::new();
+        }
+        let starts = match self.analyzer.sequence(&q) {
+            Some(seq) => self.exact_sequence_starts(id, &seq),
+            None => Vec::new(),
+        };
+        let len = match self.analyzer.unit() {
+            Unit::Byte => q.len(),
+            Unit::Char => q.chars().count(),
+        };
+        starts
+            .into_iter()
+            .map(|p| p as usize..p as usize + len)
+            .collect()
+    }
+
+    // Snippets returns the highlighted snippets of the stored document id
+    // around each match of query. When normalization changes the length of
+    // the document the snippets show the normalized text.
+    pub fn snippets(&self, id: DocID, query: &str, highlight: &Highlight) -> Vec<String> {
+        let doc = match self.document(id) {
+            None => return Vec::new(),
+            Some(doc) => doc,
+        };
+        let normalized = self.normalize(doc);
+        let text = if normalized.len() == doc.len() {
+            doc
+        } else {
+            &normalized
+        };
+
+        highlight.snippets(text, &self.matches(id, query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CaseFold, DocID, Highlight, Index, Options, Standard};
+
+    #[test]
+    fn test_highlight() {
+        let docs = [
+            "the quick brown fox jumps over the lazy dog",
+            "aaaa",
+            "Fox and FOX",
+        ];
+
+        let analyzer = Standard {
+            case_fold: CaseFold::Ascii,
+            ..Standard::default()
+        };
+        let mut stored = Index::with_analyzer(
+            analyzer.clone(),
+            Options {
+                store_documents: true,
+                ..Options::default()
+            },
+        );
+        let mut positional = Index::with_analyzer(
+            analyzer,
+            Options {
+                positions: true,
+                ..Options::default()
+            },
+        );
+        for d in docs.iter() {
+            stored.add(d);
+            positional.add(d);
+        }
+
+        for idx in [&stored, &positional] {
+            assert_eq!(idx.matches(DocID(0), "the"), vec![0..3, 31..34]);
+            assert_eq!(idx.matches(DocID(1), "aaa"), vec![0..3, 1..4]);
+            assert_eq!(idx.matches(DocID(2), "fox"), vec![0..3, 8..11]);
+            assert_eq!(idx.matches(DocID(2), "dog"), vec![]);
+            assert_eq!(idx.matches(DocID(1), ""), vec![]);
+        }
+
+        let h = Highlight {
+            open: "[".to_string(),
+            close: "]".to_string(),
+            context: 6,
+        };
+        assert_eq!(h.apply("aaaa", &[0..3, 1..4]), "[aaaa]");
+        assert_eq!(
+            stored.snippets(DocID(0), "the", &h),
+            vec!["[the] quick", " over [the] lazy "]
+        );
+        assert_eq!(
+            stored.snippets(DocID(2), "fox", &h),
+            vec!["[Fox] and [FOX]"]
+        );
+        assert_eq!(positional.snippets(DocID(2), "fox", &h).len(), 0);
+
+        // a match can't be placed by a pruned gram, so it would start inside
+        // a character
+        let mut idx = Index::with_options(Options {
+            positions: true,
+            ..Options::default()
+        });
+        for d in ["¬bz", "¬by", "¬bx", "€bc"] {
+            idx.add(d);
+        }
+        idx.prune(0.5);
+        assert_eq!(idx.query("¬bc"), vec![DocID(3)]);
+        assert_eq!(idx.matches(DocID(3), "¬bc"), vec![]);
+        assert_eq!(h.apply("€bc", &[1..5, 6..9]), "€bc");
+        assert_eq!(h.snippets("€bc", &[1..5, 6..9]), vec!["€bc"]);
+    }
+}
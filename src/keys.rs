@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use crate::T;
+
+// GramMap maps grams to values. A T is 16 bytes wide so it can hold any
+// gram an analyzer produces, but most indexes only hold plain byte
+// trigrams, which are kept under 4-byte keys; everything else, such as
+// code point, longer or field grams, goes in a second map.
+pub(crate) struct GramMap<V> {
+    trigrams: HashMap<u32, V>,
+    other: HashMap<T, V>,
+}
+
+impl<V> GramMap<V> {
+    pub(crate) fn new() -> GramMap<V> {
+        GramMap {
+            trigrams: HashMap::new(),
+            other: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn get(&self, t: &T) -> Option<&V> {
+        match t.byte_trigram() {
+            Some(k) => self.trigrams.get(&k),
+            None => self.other.get(t),
+        }
+    }
+
+    pub(crate) fn get_mut(&mut self, t: &T) -> Option<&mut V> {
+        match t.byte_trigram() {
+            Some(k) => self.trigrams.get_mut(&k),
+            None => self.other.get_mut(t),
+        }
+    }
+
+    pub(crate) fn insert(&mut self, t: T, v: V) -> Option<V> {
+        match t.byte_trigram() {
+            Some(k) => self.trigrams.insert(k, v),
+            None => self.other.insert(t, v),
+        }
+    }
+
+    pub(crate) fn remove(&mut self, t: &T) -> Option<V> {
+        match t.byte_trigram() {
+            Some(k) => self.trigrams.remove(&k),
+            None => self.other.remove(t),
+        }
+    }
+
+    // GetOrDefault returns the value of t, inserting the default first if
+    // there is none
+    pub(crate) fn get_or_default(&mut self, t: T) -> &mut V
+    where
+        V: Default,
+    {
+        match t.byte_trigram() {
+            Some(k) => self.trigrams.entry(k).or_default(),
+            None => self.other.entry(t).or_default(),
+        }
+    }
+
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = (T, &mut V)> {
+        let trigrams = self
+            .trigrams
+            .iter_mut()
+            .map(|(&k, v)| (T::from_byte_trigram(k), v));
+        let other = self.other.iter_mut().map(|(&t, v)| (t, v));
+        trigrams.chain(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GramMap;
+    use crate::{ALL_DOC_IDS, T};
+
+    #[test]
+    fn test_gram_map() {
+        let abc = T::from_bytes(b"abc");
+        assert_eq!(abc.byte_trigram(), Some(0x616263));
+        assert_eq!(T::from_byte_trigram(0x616263), abc);
+        assert_eq!(T::from_bytes(b"ab").byte_trigram(), None);
+        assert_eq!(T::from_chars(&['a', 'b', 'c']).byte_trigram(), None);
+        assert_eq!(abc.with_field(1).byte_trigram(), None);
+
+        let mut m = GramMap::<u32>::new();
+        m.insert(abc, 1);
+        m.insert(abc.with_field(1), 2);
+        *m.get_or_default(ALL_DOC_IDS) += 3;
+        assert_eq!(m.get(&abc), Some(&1));
+        assert_eq!(m.get(&abc.with_field(1)), Some(&2));
+        assert_eq!(m.get(&T::from_bytes(b"abd")), None);
+
+        let mut all: Vec<(T, u32)> = m.iter_mut().map(|(t, v)| (t, *v)).collect();
+        all.sort();
+        assert_eq!(
+            all,
+            vec![(abc, 1), (abc.with_field(1), 2), (ALL_DOC_IDS, 3)]
+        );
+
+        assert_eq!(m.remove(&abc), Some(1));
+        assert_eq!(m.get_mut(&abc), None);
+    }
+}
@@ -1,42 +1,215 @@
 use std::collections::HashMap;
 
-/// T is a trigram
+mod acl;
+mod analyzer;
+mod anchor;
+mod bm25;
+mod budget;
+mod collector;
+mod explain;
+mod facets;
+mod fields;
+mod fuzzy;
+mod highlight;
+mod keys;
+mod merge;
+mod metadata;
+mod near;
+mod normalize;
+mod planner;
+mod positions;
+mod prior;
+mod similar;
+mod sparse;
+#[rustfmt::skip]
+mod tables;
+mod text;
+
+pub use acl::Principal;
+pub use analyzer::{Analyzer, Standard};
+pub use bm25::Bm25;
+pub use budget::{Budget, CancelToken, QueryError};
+pub use collector::{Bitmap, Collector, Count, FirstN, TopScored};
+pub use explain::{PostingStatus, QueryPlan, Step, Verification};
+pub use facets::Facets;
+pub use highlight::Highlight;
+pub use metadata::{Filter, Value};
+pub use normalize::Normalization;
+pub use planner::Planner;
+pub use similar::Similarity;
+pub use sparse::SparseGrams;
+pub use text::{CaseFold, Unit};
+
+use collector::collect_docs;
+use keys::GramMap;
+
+/// T is an n-gram key: a trigram by default, of either bytes or code points
 #[derive(Eq, Hash, Clone, Copy, PartialEq, Ord, PartialOrd)]
-pub struct T(u32);
+pub struct T(u128);
 
 use std::fmt;
 
+// the top byte of a key holds its kind and length and the next one the
+// field it was found in; the gram itself is packed into the low bits, eight
+// per byte or 21 per code point, or replaced by its hash when it is too
+// long to pack
+const KIND_SHIFT: u32 = 120;
+const FIELD_SHIFT: u32 = 112;
+const FIELD_MASK: u128 = 0xFF;
+const KIND_CHARS: u128 = 0x80;
+const KIND_HASHED: u128 = 0x40;
+const LEN_MASK: u128 = 0x3F;
+const CHAR_BITS: u32 = 21;
+const CHAR_MASK: u128 = (1 << CHAR_BITS) - 1;
+
+/// the longest byte n-gram that fits in a T
+pub const MAX_BYTE_GRAM: usize = 14;
+/// the longest code point n-gram that fits in a T
+pub const MAX_CHAR_GRAM: usize = 5;
+
+// boundary padding: 0xFF never occurs in UTF-8, and 0x110000 is one past
+// the last code point, so neither can match a gram of real text
+pub(crate) const BYTE_SENTINEL: u8 = 0xFF;
+pub(crate) const CHAR_SENTINEL: u32 = 0x110000;
+
 impl fmt::Display for T {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "T({}{}{})",
-            (self.0 >> 16) as u8 as char,
-            (self.0 >> 8) as u8 as char,
-            self.0 as u8 as char,
-        )
+        write!(f, "T(")?;
+        if self.field() != 0 {
+            write!(f, "{}:", self.field())?;
+        }
+
+        let n = self.len();
+        if self.is_hashed() {
+            write!(f, "#{:016x}", self.0 as u64)?;
+        } else if self.is_char_trigram() {
+            for i in (0..n).rev() {
+                let c = ((self.0 >> (i as u32 * CHAR_BITS)) & CHAR_MASK) as u32;
+                match char::from_u32(c) {
+                    Some(c) => write!(f, "{}", c)?,
+                    None => write!(f, "\\u{{{:x}}}", c)?,
+                }
+            }
+        } else {
+            let b: Vec<u8> = (0..n).rev().map(|i| (self.0 >> (8 * i)) as u8).collect();
+            match std::str::from_utf8(&b) {
+                Ok(s) => write!(f, "{}", s)?,
+                // the gram splits a multi-byte character
+                Err(_) => {
+                    for &c in b.iter() {
+                        if c.is_ascii_graphic() || c == b' ' {
+                            write!(f, "{}", c as char)?;
+                        } else {
+                            write!(f, "\\x{:02x}", c)?;
+                        }
+                    }
+                }
+            }
+        }
+
+        write!(f, ")")
     }
 }
 
 impl fmt::Debug for T {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "T({}{}{})",
-            (self.0 >> 16) as u8 as char,
-            (self.0 >> 8) as u8 as char,
-            self.0 as u8 as char,
-        )
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl T {
+    // FromBytes packs the bytes of b, at most MAX_BYTE_GRAM of them
+    pub fn from_bytes(b: &[u8]) -> T {
+        assert!(b.len() <= MAX_BYTE_GRAM, "byte gram too long");
+
+        let mut v = 0u128;
+        for &c in b {
+            v = v << 8 | c as u128;
+        }
+        T((b.len() as u128) << KIND_SHIFT | v)
+    }
+
+    // FromLongBytes returns the key of a byte gram of any length, hashing
+    // the bytes when there are more than MAX_BYTE_GRAM of them
+    pub fn from_long_bytes(b: &[u8]) -> T {
+        if b.len() <= MAX_BYTE_GRAM {
+            return T::from_bytes(b);
+        }
+
+        // FNV-1a, which is stable across platforms and releases
+        let mut h: u64 = 0xcbf29ce484222325;
+        for &c in b {
+            h ^= c as u64;
+            h = h.wrapping_mul(0x100000001b3);
+        }
+        let len = b.len().min(LEN_MASK as usize) as u128;
+        T((KIND_HASHED | len) << KIND_SHIFT | h as u128)
+    }
+
+    // FromChars packs the code points of c, at most MAX_CHAR_GRAM of them
+    pub fn from_chars(c: &[char]) -> T {
+        let c: Vec<u32> = c.iter().map(|&ch| ch as u32).collect();
+        T::from_code_points(&c)
+    }
+
+    // FromCodePoints is FromChars for values that may be CHAR_SENTINEL
+    pub(crate) fn from_code_points(c: &[u32]) -> T {
+        assert!(c.len() <= MAX_CHAR_GRAM, "char gram too long");
+
+        let mut v = 0u128;
+        for &ch in c {
+            v = v << CHAR_BITS | ch as u128;
+        }
+        T((KIND_CHARS | c.len() as u128) << KIND_SHIFT | v)
+    }
+
+    pub fn is_char_trigram(&self) -> bool {
+        (self.0 >> KIND_SHIFT) & KIND_CHARS != 0
+    }
+
+    // WithField returns t tagged as found in field f; field 0 is the whole
+    // document
+    pub(crate) fn with_field(self, f: u8) -> T {
+        T(self.0 & !(FIELD_MASK << FIELD_SHIFT) | (f as u128) << FIELD_SHIFT)
+    }
+
+    pub(crate) fn field(&self) -> u8 {
+        ((self.0 >> FIELD_SHIFT) & FIELD_MASK) as u8
+    }
+
+    // ByteTrigram returns the three bytes of a plain byte trigram, one not
+    // tagged with a field, packed into a u32
+    pub(crate) fn byte_trigram(&self) -> Option<u32> {
+        if self.0 >> 24 == 3 << (KIND_SHIFT - 24) {
+            Some(self.0 as u32)
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn from_byte_trigram(k: u32) -> T {
+        T(3 << KIND_SHIFT | (k & 0xFF_FFFF) as u128)
+    }
+
+    fn is_hashed(&self) -> bool {
+        (self.0 >> KIND_SHIFT) & (KIND_CHARS | KIND_HASHED) == KIND_HASHED
+    }
+
+    // Len returns the number of bytes or code points in the gram; hashed
+    // grams report at most 63
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        ((self.0 >> KIND_SHIFT) & LEN_MASK) as usize
     }
 }
 
 /// DocID is a document ID
-#[derive(Debug, Eq, Copy, Clone, PartialEq, PartialOrd, Ord)]
+#[derive(Debug, Eq, Hash, Copy, Clone, PartialEq, PartialOrd, Ord)]
 pub struct DocID(i32);
 
 impl DocID {
     pub fn as_usize(&self) -> usize {
-        return self.0 as usize;
+        self.0 as usize
     }
 
     pub fn from_i32(id: i32) -> DocID {
@@ -50,7 +223,65 @@ enum Posting {
 }
 
 /// Index is a trigram index
-pub struct Index(HashMap<T, Posting>);
+pub struct Index {
+    postings: GramMap<Posting>,
+    planner: Planner,
+    analyzer: Box<dyn Analyzer>,
+    options: Options,
+    // number of unique trigrams in each document, indexed by DocID; grams
+    // pruned when the document was added aren't counted
+    lengths: Vec<u32>,
+    // number of trigrams in each document, duplicates included
+    totals: Vec<u32>,
+    // sum of totals, kept as documents come and go
+    total_len: u64,
+    // number of documents inserted and not deleted
+    live: usize,
+    // static score of each document, indexed by DocID
+    priors: Vec<f32>,
+    // document text indexed by DocID, if options.store_documents is set
+    store: Vec<Option<String>>,
+    // whether each document has been deleted, indexed by DocID
+    deleted: Vec<bool>,
+    // the positions of each gram in every document on its posting list, in
+    // step with the list, if options.positions is set
+    positions: GramMap<Vec<Vec<u32>>>,
+    // how often each gram occurs in every document on its posting list, in
+    // step with the list, if options.term_frequencies is set
+    frequencies: GramMap<Vec<u32>>,
+    // field names; a field's grams are tagged with its index plus one
+    fields: Vec<String>,
+    // number of unique grams in each field of each document, indexed by
+    // field and then DocID
+    field_lengths: Vec<Vec<u32>>,
+    // metadata values by column name, each indexed by DocID
+    columns: HashMap<String, Vec<Option<Value>>>,
+    // the documents holding each value of each column, sorted like a
+    // posting list
+    values: HashMap<String, HashMap<Value, Vec<DocID>>>,
+    // the documents each group may see, sorted like a posting list
+    groups: HashMap<String, Vec<DocID>>,
+}
+
+/// Options configures an Index
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    /// Keep a copy of every document added with add or insert, so queries
+    /// that need the text (such as query_fuzzy) can verify their candidates.
+    pub store_documents: bool,
+    /// Record where each gram occurs in each document, so query only
+    /// returns documents containing the query's grams consecutively, i.e.
+    /// the query itself, without needing the stored text.
+    pub positions: bool,
+    /// Record how often each gram occurs in each document, for rank.
+    pub term_frequencies: bool,
+}
+
+impl Default for Index {
+    fn default() -> Index {
+        Index::new()
+    }
+}
 
 #[derive(Debug)]
 struct TermFrequency {
@@ -68,7 +299,7 @@ impl PartialEq for TermFrequency {
 
 impl PartialOrd for TermFrequency {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.freq.partial_cmp(&other.freq)
+        Some(self.cmp(other))
     }
 }
 
@@ -81,134 +312,296 @@ impl Ord for TermFrequency {
 impl Eq for TermFrequency {}
 
 // a special (and invalid) trigram that holds all the document IDs
-const ALL_DOC_IDS: T = T(0xFFFFFFFF);
+const ALL_DOC_IDS: T = T(u128::MAX);
 
 // Extract returns a list of all the unique trigrams in s
 pub fn extract_trigrams(s: &str) -> Vec<T> {
-    let mut trigrams: Vec<T> = Vec::new();
-
-    if s.len() < 3 {
-        return trigrams;
-    }
-    let bytes = s.as_bytes();
-
-    for i in 0..=bytes.len() - 3 {
-        let t: T = T((bytes[i] as u32) << 16 | (bytes[i + 1] as u32) << 8 | bytes[i + 2] as u32);
-        trigrams = append_if_unique(trigrams, t);
-    }
-
-    return trigrams;
+    extract_ngrams(s, 3)
 }
 
 fn append_if_unique(mut trigrams: Vec<T>, t: T) -> Vec<T> {
     if !trigrams.contains(&t) {
         trigrams.push(t)
     }
-    return trigrams;
+    trigrams
 }
 
 // Extract All returns a list of all the unique trigrams in s
 pub fn extract_all_trigrams(s: &str, trigrams: &mut Vec<T>) {
-    let bytes = s.as_bytes();
+    extract_all_ngrams(s, 3, trigrams);
+}
 
-    if bytes.len() < 3 {
-        return;
+// ExtractNgrams returns a list of all the unique byte n-grams in s
+pub fn extract_ngrams(s: &str, n: usize) -> Vec<T> {
+    let mut all = Vec::<T>::new();
+    extract_all_ngrams(s, n, &mut all);
+
+    let mut grams = Vec::<T>::with_capacity(all.len());
+    for t in all {
+        grams = append_if_unique(grams, t);
+    }
+
+    grams
+}
+
+// ExtractAllNgrams appends every byte n-gram in s to grams
+pub fn extract_all_ngrams(s: &str, n: usize, grams: &mut Vec<T>) {
+    for w in s.as_bytes().windows(n) {
+        grams.push(T::from_bytes(w));
+    }
+}
+
+// ExtractChars returns a list of all the unique code point trigrams in s
+pub fn extract_char_trigrams(s: &str) -> Vec<T> {
+    extract_char_ngrams(s, 3)
+}
+
+// ExtractAllChars appends every code point trigram in s to trigrams
+pub fn extract_all_char_trigrams(s: &str, trigrams: &mut Vec<T>) {
+    extract_all_char_ngrams(s, 3, trigrams);
+}
+
+// ExtractCharNgrams returns a list of all the unique code point n-grams in s
+pub fn extract_char_ngrams(s: &str, n: usize) -> Vec<T> {
+    let mut all = Vec::<T>::new();
+    extract_all_char_ngrams(s, n, &mut all);
+
+    let mut grams = Vec::<T>::with_capacity(all.len());
+    for t in all {
+        grams = append_if_unique(grams, t);
+    }
+
+    grams
+}
+
+// ExtractAllCharNgrams appends every code point n-gram in s to grams
+pub fn extract_all_char_ngrams(s: &str, n: usize, grams: &mut Vec<T>) {
+    let chars: Vec<char> = s.chars().collect();
+
+    for w in chars.windows(n) {
+        grams.push(T::from_chars(w));
+    }
+}
+
+// PadBytes surrounds the bytes of s with n-1 sentinels at the requested
+// ends, so that each byte next to a boundary starts or ends a full gram
+pub(crate) fn pad_bytes(s: &str, n: usize, start: bool, end: bool) -> Vec<u8> {
+    let pad = n.saturating_sub(1);
+    let mut b = Vec::<u8>::with_capacity(s.len() + 2 * pad);
+    if start {
+        b.resize(pad, BYTE_SENTINEL);
+    }
+    b.extend_from_slice(s.as_bytes());
+    if end {
+        b.resize(b.len() + pad, BYTE_SENTINEL);
+    }
+    b
+}
+
+// PadChars is PadBytes for code points
+pub(crate) fn pad_chars(s: &str, n: usize, start: bool, end: bool) -> Vec<u32> {
+    let pad = n.saturating_sub(1);
+    let mut c = Vec::<u32>::with_capacity(s.len() + 2 * pad);
+    if start {
+        c.resize(pad, CHAR_SENTINEL);
+    }
+    c.extend(s.chars().map(|ch| ch as u32));
+    if end {
+        c.resize(c.len() + pad, CHAR_SENTINEL);
+    }
+    c
+}
+
+// ExtractAllPaddedNgrams appends every byte n-gram of s, padded at both ends
+// with sentinels, to grams. Even the empty document yields a gram.
+pub fn extract_all_padded_ngrams(s: &str, n: usize, grams: &mut Vec<T>) {
+    for w in pad_bytes(s, n, true, true).windows(n) {
+        grams.push(T::from_bytes(w));
     }
+}
 
-    for i in 0..=bytes.len() - 3 {
-        let t: T = T((bytes[i] as u32) << 16 | (bytes[i + 1] as u32) << 8 | bytes[i + 2] as u32);
-        trigrams.push(t);
+// ExtractAllPaddedCharNgrams is ExtractAllPaddedNgrams for code points
+pub fn extract_all_padded_char_ngrams(s: &str, n: usize, grams: &mut Vec<T>) {
+    for w in pad_chars(s, n, true, true).windows(n) {
+        grams.push(T::from_code_points(w));
     }
 }
 
 impl Index {
+    // New returns an empty index
+    pub fn new() -> Index {
+        Index::with_options(Options::default())
+    }
+
+    // WithOptions returns an empty index configured by options
+    pub fn with_options(options: Options) -> Index {
+        Index::with_analyzer(Standard::default(), options)
+    }
+
+    // WithAnalyzer returns an empty index that extracts trigrams with analyzer
+    pub fn with_analyzer<A: Analyzer + 'static>(analyzer: A, options: Options) -> Index {
+        let mut postings = GramMap::<Posting>::new();
+        postings.insert(ALL_DOC_IDS, Posting::List(Vec::new()));
+
+        Index {
+            postings,
+            planner: Planner::default(),
+            analyzer: Box::new(analyzer),
+            options,
+            lengths: Vec::new(),
+            totals: Vec::new(),
+            total_len: 0,
+            live: 0,
+            priors: Vec::new(),
+            store: Vec::new(),
+            deleted: Vec::new(),
+            positions: GramMap::new(),
+            frequencies: GramMap::new(),
+            fields: Vec::new(),
+            field_lengths: Vec::new(),
+            columns: HashMap::new(),
+            values: HashMap::new(),
+            groups: HashMap::new(),
+        }
+    }
+
     // NewIndex returns an index for the strings in docs
     pub fn new_with_documents(docs: Vec<&str>) -> Index {
-        let mut idx = HashMap::<T, Posting>::new();
-        let mut all_doc_ids = Vec::<DocID>::new();
-        let mut trigrams = Vec::<T>::new();
+        let mut idx = Index::new();
 
         for (id, &d) in docs.iter().enumerate() {
-            extract_all_trigrams(d, &mut trigrams);
-            let docid = DocID(id as i32);
-
-            all_doc_ids.push(docid);
-
-            for t in trigrams.iter() {
-                match idx.get_mut(&t) {
-                    None => {
-                        idx.insert(t.clone(), Posting::List(vec![docid]));
-                    }
-                    Some(oidxt) => match oidxt {
-                        Posting::Pruned => panic!("pruned list found during index construction"),
-                        Posting::List(idxt) => match idxt.last() {
-                            None => idxt.push(docid),
-                            Some(did) => {
-                                if did != &docid {
-                                    idxt.push(docid);
-                                }
-                            }
-                        },
-                    },
-                }
-            }
-            trigrams.clear();
+            idx.insert(d, DocID(id as i32));
         }
 
-        idx.insert(ALL_DOC_IDS, Posting::List(all_doc_ids));
-
-        return Index(idx);
+        idx
     }
 
     pub fn add(&mut self, s: &str) -> DocID {
         let id = DocID(self.get_all_docs().len() as i32);
         self.insert(s, id);
-        return id;
+        id
     }
 
-    pub fn add_trigrams(&mut self, ts: &Vec<T>) -> DocID {
+    pub fn add_trigrams(&mut self, ts: &[T]) -> DocID {
         let id = DocID(self.get_all_docs().len() as i32);
         self.insert_trigrams(ts, id);
-        return id;
+        id
     }
 
     pub fn insert(&mut self, s: &str, id: DocID) {
         let mut ts = Vec::<T>::new();
-        extract_all_trigrams(s, &mut ts);
+        self.extract_document(s, &mut ts);
         self.insert_trigrams(&ts, id);
+        self.store_document(s, id);
     }
 
-    pub fn insert_trigrams(&mut self, ts: &Vec<T>, id: DocID) {
+    // StoreDocument keeps the text of id if documents are being stored
+    pub(crate) fn store_document(&mut self, s: &str, id: DocID) {
+        if self.options.store_documents {
+            if self.store.len() <= id.as_usize() {
+                self.store.resize(id.as_usize() + 1, None);
+            }
+            self.store[id.as_usize()] = Some(s.to_string());
+        }
+    }
+
+    // Document returns the stored text of id, if documents are being stored
+    pub fn document(&self, id: DocID) -> Option<&str> {
+        match self.store.get(id.as_usize()) {
+            Some(Some(s)) => Some(s),
+            _ => None,
+        }
+    }
+
+    // IsDeleted reports whether id was deleted and not inserted again
+    pub fn is_deleted(&self, id: DocID) -> bool {
+        self.deleted.get(id.as_usize()).copied().unwrap_or(false)
+    }
+
+    pub fn insert_trigrams(&mut self, ts: &[T], id: DocID) {
+        if let Some(deleted) = self.deleted.get_mut(id.as_usize()) {
+            *deleted = false;
+        }
+        if self.options.positions {
+            self.insert_positions(ts, id);
+        }
+        if self.options.term_frequencies {
+            self.insert_frequencies(ts, id);
+        }
+
+        // grams shorter than the analyzer's only answer short queries, and
+        // field grams repeat the document's; neither count towards its length
+        let n = self.analyzer.gram_len();
+        let (mut unique, mut total) = (0u32, 0u32);
         for t in ts.iter() {
-            match self.0.get_mut(&t) {
+            let counted = t.len() >= n && t.field() == 0;
+            if counted {
+                total += 1;
+            }
+
+            match self.postings.get_mut(t) {
                 None => {
-                    self.0.insert(t.clone(), Posting::List(vec![id]));
+                    self.postings.insert(*t, Posting::List(vec![id]));
+                    if counted {
+                        unique += 1;
+                    }
                 }
                 Some(oidxt) => match oidxt {
                     Posting::Pruned => { /* trigram post list has been pruned; it must be kept empty */
                     }
-                    Posting::List(idxt) => match idxt.last() {
-                        None => idxt.push(id),
-                        Some(did) => {
-                            if did != &id {
-                                idxt.push(id);
+                    Posting::List(idxt) => {
+                        if idxt.last() != Some(&id) {
+                            idxt.push(id);
+                            if counted {
+                                unique += 1;
                             }
                         }
-                    },
+                    }
                 },
             }
         }
 
+        if self.lengths.len() <= id.as_usize() {
+            self.lengths.resize(id.as_usize() + 1, 0);
+            self.totals.resize(id.as_usize() + 1, 0);
+        }
+        self.lengths[id.as_usize()] = unique;
+        self.total_len -= self.totals[id.as_usize()] as u64;
+        self.total_len += total as u64;
+        self.totals[id.as_usize()] = total;
+        self.live += 1;
+
         let all = self.get_all_docs_mut();
         all.push(id);
     }
 
     pub fn delete(&mut self, s: &str, id: DocID) {
         let mut ts = Vec::<T>::new();
-        extract_all_trigrams(s, &mut ts);
+        self.extract_document(s, &mut ts);
+
+        if let Some(doc) = self.store.get_mut(id.as_usize()) {
+            *doc = None;
+        }
+
+        self.delete_trigrams(&ts, id);
+    }
+
+    pub fn delete_trigrams(&mut self, ts: &[T], id: DocID) {
+        if self.deleted.len() <= id.as_usize() {
+            self.deleted.resize(id.as_usize() + 1, false);
+        }
+        if !self.deleted[id.as_usize()] {
+            self.deleted[id.as_usize()] = true;
+            self.live = self.live.saturating_sub(1);
+        }
+
+        if let Some(total) = self.totals.get_mut(id.as_usize()) {
+            self.total_len -= *total as u64;
+            *total = 0;
+        }
 
         for t in ts.iter() {
-            match self.0.get_mut(&t) {
+            match self.postings.get_mut(t) {
                 None => {
                     // odd, no posting list present for this trigram
                     continue;
@@ -224,13 +617,21 @@ impl Index {
                         }
                         1 => {
                             if idxt[0] == id {
-                                self.0.remove(t);
+                                self.postings.remove(t);
+                                self.positions.remove(t);
+                                self.frequencies.remove(t);
                                 continue;
                             }
                         }
                         _ => {
                             if let Ok(n) = idxt.binary_search(&id) {
                                 idxt.remove(n);
+                                if let Some(p) = self.positions.get_mut(t) {
+                                    p.remove(n);
+                                }
+                                if let Some(f) = self.frequencies.get_mut(t) {
+                                    f.remove(n);
+                                }
                             }
                         }
                     },
@@ -240,12 +641,18 @@ impl Index {
     }
 
     pub fn query(&self, s: &str) -> Vec<DocID> {
-        let ts = extract_trigrams(s);
-        return self.query_trigrams(&ts);
+        budget::unlimited(|b| self.query_with_budget(s, b))
+    }
+
+    // QueryWithBudget is like Query but gives up once budget expires
+    pub fn query_with_budget(&self, s: &str, budget: &Budget) -> Result<Vec<DocID>, QueryError> {
+        let ts = self.extract_query(s);
+        let seq = self.position_sequence(s);
+        collect_docs(|docs| self.run(&ts, None, seq.as_deref(), budget, None, docs))
     }
 
     fn get_all_docs(&self) -> &Vec<DocID> {
-        let all = match self.0.get(&ALL_DOC_IDS).unwrap() {
+        let all = match self.postings.get(&ALL_DOC_IDS).unwrap() {
             Posting::Pruned => panic!("all docs pruned"),
             Posting::List(l) => l,
         };
@@ -253,7 +660,7 @@ impl Index {
     }
 
     fn get_all_docs_mut(&mut self) -> &mut Vec<DocID> {
-        let all = match self.0.get_mut(&ALL_DOC_IDS).unwrap() {
+        let all = match self.postings.get_mut(&ALL_DOC_IDS).unwrap() {
             Posting::Pruned => panic!("all docs pruned"),
             Posting::List(l) => l,
         };
@@ -264,12 +671,11 @@ impl Index {
         self.get_all_docs().clone()
     }
 
-    pub fn trigram_counts(&self, trigrams: &Vec<T>) -> Vec<i32> {
-        let mut counts = Vec::<i32>::new();
-        counts.reserve(trigrams.len());
+    pub fn trigram_counts(&self, trigrams: &[T]) -> Vec<i32> {
+        let mut counts = Vec::<i32>::with_capacity(trigrams.len());
 
         for t in trigrams {
-            let n = match self.0.get(t) {
+            let n = match self.postings.get(t) {
                 None => 0,
                 Some(l) => match l {
                     Posting::Pruned => 0,
@@ -282,56 +688,16 @@ impl Index {
         counts
     }
 
-    pub fn query_trigrams(&self, trigrams: &Vec<T>) -> Vec<DocID> {
-        if trigrams.len() == 0 {
-            return self.copy_all_docs();
-        }
-
-        let mut freqs = Vec::<TermFrequency>::with_capacity(trigrams.len());
-        for t in trigrams.iter() {
-            let d = match self.0.get(t) {
-                None => return Vec::<DocID>::new(),
-                Some(d) => d,
-            };
-            freqs.push(TermFrequency {
-                t: *t,
-                freq: match d {
-                    Posting::Pruned => 0,
-                    Posting::List(d) => d.len(),
-                },
-            });
-        }
-
-        freqs.sort_unstable();
-
-        let mut nonzero = 0usize;
-        while nonzero < freqs.len() && freqs[nonzero].freq == 0 {
-            nonzero += 1;
-        }
-
-        // all the trigrams have been pruned; return all docs
-        if nonzero == freqs.len() {
-            return self.copy_all_docs();
-        }
-        let mut ts = Vec::<T>::new();
-        ts.reserve(freqs.len() - nonzero);
-
-        // skip over pruned trigrams
-        for tf in freqs[nonzero..freqs.len()].iter() {
-            ts.push(tf.t);
-        }
-
-        let (first, rest) = ts.split_first().unwrap();
-
-        let docs = self.0.get(first);
+    pub fn query_trigrams(&self, trigrams: &[T]) -> Vec<DocID> {
+        budget::unlimited(|b| self.query_trigrams_with_budget(trigrams, b))
+    }
 
-        match docs {
-            None => return Vec::<DocID>::new(),
-            Some(docs) => match docs {
-                Posting::Pruned => return Vec::<DocID>::new(),
-                Posting::List(d) => return self.filter(d, &rest.to_vec()),
-            },
-        };
+    pub fn query_trigrams_with_budget(
+        &self,
+        trigrams: &[T],
+        budget: &Budget,
+    ) -> Result<Vec<DocID>, QueryError> {
+        collect_docs(|docs| self.run(trigrams, None, None, budget, None, docs))
     }
 
     pub fn prune(&mut self, percent: f64) -> usize {
@@ -340,13 +706,15 @@ impl Index {
         let mut pruned = 0usize;
 
         // Update all values
-        for (t, v) in self.0.iter_mut() {
+        for (t, v) in self.postings.iter_mut() {
             match v {
                 Posting::Pruned => continue,
                 Posting::List(l) => {
-                    if l.len() > max_documents && t != &ALL_DOC_IDS {
+                    if l.len() > max_documents && t != ALL_DOC_IDS {
                         pruned += 1;
                         *v = Posting::Pruned;
+                        self.positions.remove(&t);
+                        self.frequencies.remove(&t);
                     }
                 }
             }
@@ -356,84 +724,27 @@ impl Index {
     }
 
     // Filter removes documents that don't contain the specified trigrams
-    pub fn filter(&self, docs: &Vec<DocID>, ts: &Vec<T>) -> Vec<DocID> {
-        // no provided filter trigrams
-        if ts.len() == 0 {
-            return docs.clone();
-        }
-
-        // interesting implementation detail:
-        // we don't want to repurpose/alter docs since it's typically
-        // a live postings list, hence allocating a result slice
-        // however, upon subsequent loop runs we do repurpose the input
-        // as the output, because at that point its safe for reuse
-
-        let mut result = Vec::<DocID>::new();
-        result.resize(docs.len(), DocID(0));
-
-        let mut first = true;
-
-        for t in ts.iter() {
-            let d = match self.0.get(t) {
-                None => return Vec::<DocID>::new(),
-                Some(d) => d,
-            };
-
-            let d = match d {
-                Posting::Pruned => continue,
-                Posting::List(l) => l,
-            };
-
-            if first {
-                intersect3(&mut result, &docs, d);
-                first = false;
-            } else {
-                intersect2(&mut result, d);
-            }
-        }
-
-        return result;
+    pub fn filter(&self, docs: &[DocID], ts: &[T]) -> Vec<DocID> {
+        budget::unlimited(|b| self.filter_with_budget(docs, ts, b))
     }
-}
-
-// intersect intersects the input slices and puts the output in result slice
-// note that result may be backed by the same array as a or b, since
-// we only add docs that also exist in both inputs, it's guaranteed that we
-// never overwrite/clobber the input, as long as result's start and len are proper
-fn intersect3(result: &mut Vec<DocID>, a: &Vec<DocID>, b: &Vec<DocID>) {
-    let mut aidx = 0usize;
-    let mut bidx = 0usize;
-    let mut ridx: usize = 0usize;
 
-    'scan: while aidx < a.len() && bidx < b.len() {
-        if a[aidx] == b[bidx] {
-            result[ridx] = a[aidx];
-            ridx += 1;
-            aidx += 1;
-            bidx += 1;
-            if aidx == a.len() || bidx == b.len() {
-                break 'scan;
-            }
-        }
-
-        while a[aidx] < b[bidx] {
-            aidx += 1;
-            if aidx == a.len() {
-                break 'scan;
-            }
-        }
-
-        while a[aidx] > b[bidx] {
-            bidx += 1;
-            if bidx == b.len() {
-                break 'scan;
-            }
-        }
+    // FilterWithBudget is like Filter but checks budget as it goes; on
+    // expiry the documents not yet ruled out are returned inside the error
+    pub fn filter_with_budget(
+        &self,
+        docs: &[DocID],
+        ts: &[T],
+        budget: &Budget,
+    ) -> Result<Vec<DocID>, QueryError> {
+        let lists = match self.filter_lists(ts) {
+            None => return Ok(Vec::new()),
+            Some(lists) => lists,
+        };
+        collect_docs(|result| self.evaluate(docs, lists, None, budget, 0, None, result))
     }
-    result.truncate(ridx);
 }
 
-fn intersect2(a: &mut Vec<DocID>, b: &Vec<DocID>) {
+fn intersect2(a: &mut Vec<DocID>, b: &[DocID]) {
     let mut aidx = 0usize;
     let mut bidx = 0usize;
     let mut ridx: usize = 0usize;
@@ -501,4 +812,72 @@ mod tests {
 
         test_query!("zottle", vec![DocID(7)]);
     }
+
+    #[test]
+    fn test_filter_pruned() {
+        let docs = vec!["foo", "foobar", "foobfoo", "quxzoot"];
+        let mut idx = Index::new_with_documents(docs);
+        idx.prune(0.5);
+
+        // every trigram of "foo" is pruned, so none of them narrows docs
+        let docs = vec![DocID(1), DocID(3)];
+        assert_eq!(idx.filter(&docs, &extract_trigrams("foo")), docs);
+    }
+
+    #[test]
+    fn test_query_budget() {
+        let docs = vec!["foo", "foobar", "foobfoo", "quxzoot", "zotzot", "azotfoba"];
+        let idx = Index::new_with_documents(docs);
+
+        let got = idx.query_with_budget("foob", &Budget::unlimited());
+        assert_eq!(got, Ok(vec![DocID(1), DocID(2)]));
+
+        let token = CancelToken::new();
+        token.cancel();
+        let got = idx.query_with_budget("foob", &Budget::unlimited().cancel_on(token));
+        assert!(matches!(got, Err(QueryError::Cancelled(_))));
+
+        // an expired deadline still returns a superset of the real result
+        let budget = Budget::with_deadline(std::time::Instant::now());
+        match idx.query_with_budget("foob", &budget) {
+            Err(QueryError::Timeout(partial)) => {
+                assert!(partial.contains(&DocID(1)) && partial.contains(&DocID(2)))
+            }
+            got => panic!("expected timeout, got {:?}", got),
+        }
+    }
+
+    #[test]
+    fn test_ngram_length() {
+        assert_eq!(format!("{:?}", extract_ngrams("abab", 2)), "[T(ab), T(ba)]");
+        assert_eq!(format!("{}", T::from_bytes(b"quux")), "T(quux)");
+        assert_eq!(
+            format!("{}", T::from_chars(&['м', 'и', 'р', 'ы'])),
+            "T(миры)"
+        );
+        assert_ne!(T::from_bytes(b"ab"), T::from_bytes(b"\0ab"));
+        assert_ne!(T::from_bytes(b"abc"), T::from_chars(&['a', 'b', 'c']));
+
+        let bigrams = Standard {
+            n: 2,
+            ..Standard::default()
+        };
+        let mut idx = Index::with_analyzer(bigrams, Options::default());
+        idx.add("go");
+        idx.add("og");
+        idx.add("gogo");
+        assert_eq!(idx.query("go"), vec![DocID(0), DocID(2)]);
+        assert_eq!(idx.query("ogo"), vec![DocID(2)]);
+
+        let fourgrams = Standard {
+            n: 4,
+            ..Standard::default()
+        };
+        let mut idx = Index::with_analyzer(fourgrams, Options::default());
+        idx.add("foobar");
+        idx.add("foo bar");
+        assert_eq!(idx.query("obar"), vec![DocID(0)]);
+        assert_eq!(idx.query("o ba"), vec![DocID(1)]);
+        assert_eq!(idx.query_ignore_case("FOOBAR"), vec![DocID(0)]);
+    }
 }
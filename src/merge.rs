@@ -0,0 +1,216 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::{DocID, Index, Posting};
+
+// CountMerge walks several sorted posting lists at once, yielding every
+// document together with the number of lists it appears in
+pub(crate) struct CountMerge<'a> {
+    lists: Vec<&'a [DocID]>,
+    pos: Vec<usize>,
+    heap: BinaryHeap<Reverse<(DocID, usize)>>,
+}
+
+impl<'a> CountMerge<'a> {
+    pub(crate) fn new(lists: Vec<&'a [DocID]>) -> CountMerge<'a> {
+        let mut heap = BinaryHeap::with_capacity(lists.len());
+        for (i, l) in lists.iter().enumerate() {
+            if let Some(&d) = l.first() {
+                heap.push(Reverse((d, i)));
+            }
+        }
+
+        CountMerge {
+            pos: vec![0; lists.len()],
+            lists,
+            heap,
+        }
+    }
+
+    fn advance(&mut self, i: usize) {
+        self.pos[i] += 1;
+        if let Some(&d) = self.lists[i].get(self.pos[i]) {
+            self.heap.push(Reverse((d, i)));
+        }
+    }
+}
+
+impl<'a> Iterator for CountMerge<'a> {
+    type Item = (DocID, usize);
+
+    fn next(&mut self) -> Option<(DocID, usize)> {
+        let Reverse((doc, i)) = self.heap.pop()?;
+        self.advance(i);
+
+        let mut count = 1;
+        while let Some(&Reverse((d, j))) = self.heap.peek() {
+            if d != doc {
+                break;
+            }
+            self.heap.pop();
+            self.advance(j);
+            count += 1;
+        }
+
+        Some((doc, count))
+    }
+}
+
+// Cursor walks a sorted posting list
+pub(crate) struct Cursor<'a> {
+    list: &'a [DocID],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub(crate) fn new(list: &'a [DocID]) -> Cursor<'a> {
+        Cursor { list, pos: 0 }
+    }
+
+    pub(crate) fn doc(&self) -> DocID {
+        self.list[self.pos]
+    }
+
+    pub(crate) fn done(&self) -> bool {
+        self.pos >= self.list.len()
+    }
+
+    pub(crate) fn advance(&mut self) {
+        self.pos += 1;
+    }
+
+    // Rest returns the documents from the cursor on
+    pub(crate) fn rest(&self) -> &'a [DocID] {
+        &self.list[self.pos..]
+    }
+
+    // Seek moves the cursor to the first document not less than d. It
+    // gallops ahead, since the target is usually close by.
+    pub(crate) fn seek(&mut self, d: DocID) {
+        // dense lists are mostly a step or two away
+        for _ in 0..4 {
+            match self.list.get(self.pos) {
+                Some(&x) if x < d => self.pos += 1,
+                _ => return,
+            }
+        }
+
+        let rest = &self.list[self.pos..];
+        let mut hi = 1;
+        while hi < rest.len() && rest[hi] < d {
+            hi *= 2;
+        }
+        let lo = hi / 2;
+        let hi = (hi + 1).min(rest.len());
+        self.pos += lo + rest[lo..hi].partition_point(|&x| x < d);
+    }
+}
+
+// MinMatch yields the documents present in at least min of the lists,
+// together with the number of lists they appear in. It is a WAND-style
+// traversal with unit weights: cursors lagging behind the min-th smallest
+// document cannot reach the threshold on their own, so they skip ahead to
+// it instead of visiting every posting.
+pub(crate) struct MinMatch<'a> {
+    min: usize,
+    // sorted by current document
+    cursors: Vec<Cursor<'a>>,
+}
+
+impl<'a> MinMatch<'a> {
+    pub(crate) fn new(lists: Vec<&'a [DocID]>, min: usize) -> MinMatch<'a> {
+        let mut cursors: Vec<Cursor<'a>> = lists
+            .into_iter()
+            .filter(|l| !l.is_empty())
+            .map(|list| Cursor { list, pos: 0 })
+            .collect();
+        cursors.sort_by_key(|c| c.doc());
+
+        MinMatch {
+            min: min.max(1),
+            cursors,
+        }
+    }
+}
+
+impl<'a> Iterator for MinMatch<'a> {
+    type Item = (DocID, usize);
+
+    fn next(&mut self) -> Option<(DocID, usize)> {
+        while self.cursors.len() >= self.min {
+            let pivot = self.cursors[self.min - 1].doc();
+
+            if self.cursors[0].doc() == pivot {
+                let count = self.cursors.iter().take_while(|c| c.doc() == pivot).count();
+                for c in self.cursors[..count].iter_mut() {
+                    c.pos += 1;
+                }
+                self.cursors.retain(|c| !c.done());
+                self.cursors.sort_by_key(|c| c.doc());
+                return Some((pivot, count));
+            }
+
+            for c in self.cursors[..self.min - 1].iter_mut() {
+                c.seek(pivot);
+            }
+            self.cursors.retain(|c| !c.done());
+            self.cursors.sort_by_key(|c| c.doc());
+        }
+
+        None
+    }
+}
+
+impl Index {
+    // QueryMinMatch returns the documents containing at least
+    // min_should_match of the unique trigrams of s. Pruned trigrams are
+    // assumed to be present in every document.
+    pub fn query_min_match(&self, s: &str, min_should_match: usize) -> Vec<DocID> {
+        let ts = self.extract_query(s);
+        let mut required = min_should_match.min(ts.len());
+
+        let mut lists = Vec::with_capacity(ts.len());
+        for t in ts.iter() {
+            match self.postings.get(t) {
+                None => continue,
+                Some(Posting::Pruned) => required = required.saturating_sub(1),
+                Some(Posting::List(l)) => lists.push(l.as_slice()),
+            }
+        }
+
+        if required == 0 {
+            return self.copy_all_docs();
+        }
+
+        MinMatch::new(lists, required).map(|(d, _)| d).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MinMatch;
+    use crate::{DocID, Index};
+
+    #[test]
+    fn test_min_match() {
+        let a = [DocID(1), DocID(3), DocID(5), DocID(7)];
+        let b = [DocID(3), DocID(4), DocID(7)];
+        let c = [DocID(0), DocID(7), DocID(9)];
+
+        let got: Vec<_> = MinMatch::new(vec![&a, &b, &c], 2).collect();
+        assert_eq!(got, vec![(DocID(3), 2), (DocID(7), 3)]);
+
+        let got: Vec<_> = MinMatch::new(vec![&a, &b, &c], 1).map(|(d, _)| d).collect();
+        assert_eq!(got.len(), 7);
+
+        let docs = vec!["foobar", "fooxar", "barfoo", "quxqux"];
+        let idx = Index::new_with_documents(docs);
+
+        // a typo in the query still finds "foobar" through "bar"
+        assert_eq!(idx.query("foxbar"), vec![]);
+        assert_eq!(idx.query_min_match("foxbar", 1), vec![DocID(0), DocID(2)]);
+        assert_eq!(idx.query_min_match("foobar", 4), vec![DocID(0)]);
+        assert_eq!(idx.query_min_match("foobar", 2), vec![DocID(0), DocID(2)]);
+        assert_eq!(idx.query_min_match("fo", 1).len(), 4);
+    }
+}
@@ -0,0 +1,303 @@
+use std::cmp::Ordering;
+
+use crate::budget;
+use crate::collector::collect_docs;
+use crate::merge::CountMerge;
+use crate::{intersect2, Budget, Collector, DocID, Index, QueryError};
+
+/// Value is a metadata value attached to a document: a number such as a
+/// timestamp, or a tag such as a repository or language
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Value {
+    Int(i64),
+    Str(String),
+}
+
+impl From<i64> for Value {
+    fn from(v: i64) -> Value {
+        Value::Int(v)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(v: &str) -> Value {
+        Value::Str(v.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(v: String) -> Value {
+        Value::Str(v)
+    }
+}
+
+impl Value {
+    // Compare orders values of the same kind; numbers and tags don't compare
+    pub(crate) fn compare(&self, other: &Value) -> Option<Ordering> {
+        match (self, other) {
+            (Value::Int(a), Value::Int(b)) => Some(a.cmp(b)),
+            (Value::Str(a), Value::Str(b)) => Some(a.cmp(b)),
+            _ => None,
+        }
+    }
+}
+
+/// Filter restricts documents by their metadata
+#[derive(Debug, Clone)]
+pub enum Filter {
+    /// the column holds exactly this value
+    Eq(String, Value),
+    /// the column holds a value between min and max, both inclusive; a
+    /// missing bound is unbounded
+    Range(String, Option<Value>, Option<Value>),
+    /// every filter holds
+    And(Vec<Filter>),
+}
+
+impl Filter {
+    pub fn eq<V: Into<Value>>(column: &str, v: V) -> Filter {
+        Filter::Eq(column.to_string(), v.into())
+    }
+
+    pub fn range<V: Into<Value>>(column: &str, min: V, max: V) -> Filter {
+        Filter::Range(column.to_string(), Some(min.into()), Some(max.into()))
+    }
+
+    pub fn at_least<V: Into<Value>>(column: &str, min: V) -> Filter {
+        Filter::Range(column.to_string(), Some(min.into()), None)
+    }
+
+    pub fn at_most<V: Into<Value>>(column: &str, max: V) -> Filter {
+        Filter::Range(column.to_string(), None, Some(max.into()))
+    }
+
+    // And returns a filter requiring both self and other
+    pub fn and(self, other: Filter) -> Filter {
+        match self {
+            Filter::And(mut fs) => {
+                fs.push(other);
+                Filter::And(fs)
+            }
+            f => Filter::And(vec![f, other]),
+        }
+    }
+
+    // Docs returns the documents passing the filter, read from the value
+    // postings, or None if it has no clause and so passes every document
+    fn docs(&self, idx: &Index) -> Option<Vec<DocID>> {
+        let mut docs: Option<Vec<DocID>> = None;
+        let mut restrict = |list: &[DocID]| match docs.as_mut() {
+            None => docs = Some(list.to_vec()),
+            Some(d) => intersect2(d, list),
+        };
+
+        let mut pending = vec![self];
+        while let Some(f) = pending.pop() {
+            match f {
+                Filter::Eq(c, v) => restrict(idx.value_postings(c, v)),
+                Filter::Range(c, min, max) => restrict(&idx.range_postings(c, min, max)),
+                Filter::And(fs) => pending.extend(fs.iter()),
+            }
+        }
+        docs
+    }
+}
+
+// InRange reports whether v lies between min and max, both inclusive; a
+// missing bound is unbounded
+fn in_range(v: &Value, min: &Option<Value>, max: &Option<Value>) -> bool {
+    let above = min
+        .as_ref()
+        .is_none_or(|min| matches!(v.compare(min), Some(Ordering::Greater | Ordering::Equal)));
+    let below = max
+        .as_ref()
+        .is_none_or(|max| matches!(v.compare(max), Some(Ordering::Less | Ordering::Equal)));
+    above && below
+}
+
+impl Index {
+    // SetMetadata attaches value to document id under column
+    pub fn set_metadata<V: Into<Value>>(&mut self, id: DocID, column: &str, value: V) {
+        let value = value.into();
+        let values = self.columns.entry(column.to_string()).or_default();
+        if values.len() <= id.as_usize() {
+            values.resize(id.as_usize() + 1, None);
+        }
+        let old = values[id.as_usize()].replace(value.clone());
+
+        let postings = self.values.entry(column.to_string()).or_default();
+        if let Some(old) = old {
+            if let Some(list) = postings.get_mut(&old) {
+                if let Ok(i) = list.binary_search(&id) {
+                    list.remove(i);
+                }
+            }
+        }
+        let list = postings.entry(value).or_default();
+        if let Err(i) = list.binary_search(&id) {
+            list.insert(i, id);
+        }
+    }
+
+    // ValuePostings returns the documents whose column holds value
+    fn value_postings(&self, column: &str, value: &Value) -> &[DocID] {
+        self.values
+            .get(column)
+            .and_then(|p| p.get(value))
+            .map_or(&[], |l| l.as_slice())
+    }
+
+    // RangePostings returns the documents whose column holds a value
+    // between min and max, merging the postings of every such value
+    fn range_postings(&self, column: &str, min: &Option<Value>, max: &Option<Value>) -> Vec<DocID> {
+        let lists = match self.values.get(column) {
+            None => return Vec::new(),
+            Some(p) => p
+                .iter()
+                .filter(|(v, _)| in_range(v, min, max))
+                .map(|(_, l)| l.as_slice())
+                .collect(),
+        };
+        CountMerge::new(lists).map(|(d, _)| d).collect()
+    }
+
+    // Metadata returns the value of column for document id
+    pub fn metadata(&self, id: DocID, column: &str) -> Option<&Value> {
+        self.columns.get(column)?.get(id.as_usize())?.as_ref()
+    }
+
+    // FilterDocs returns the documents passing filter, in DocID order
+    pub fn filter_docs(&self, filter: &Filter) -> Vec<DocID> {
+        filter.docs(self).unwrap_or_else(|| self.copy_all_docs())
+    }
+
+    // QueryFiltered is like Query but only returns documents passing
+    // filter. The documents passing it come from the value postings, a
+    // range merging those of every value it spans, and join the planner's
+    // intersections, so when they are the most selective they are applied
+    // before any posting list.
+    pub fn query_filtered(&self, s: &str, filter: &Filter) -> Vec<DocID> {
+        budget::unlimited(|b| self.query_filtered_with_budget(s, filter, b))
+    }
+
+    // QueryFilteredWithBudget is like QueryFiltered but stops when budget
+    // expires
+    pub fn query_filtered_with_budget(
+        &self,
+        s: &str,
+        filter: &Filter,
+        budget: &Budget,
+    ) -> Result<Vec<DocID>, QueryError> {
+        collect_docs(|docs| self.run_filtered(s, filter, budget, docs))
+    }
+
+    // QueryFilteredCollect is like QueryFiltered but hands the matching
+    // documents to collector, so counts and pages only cover documents
+    // passing filter
+    pub fn query_filtered_collect(&self, s: &str, filter: &Filter, collector: &mut dyn Collector) {
+        budget::unlimited(|b| self.run_filtered(s, filter, b, collector));
+    }
+
+    // RunFiltered evaluates s restricted to the documents passing filter
+    fn run_filtered<C: Collector + ?Sized>(
+        &self,
+        s: &str,
+        filter: &Filter,
+        budget: &Budget,
+        collector: &mut C,
+    ) -> Result<(), QueryError> {
+        let within = filter.docs(self);
+        let ts = self.extract_query(s);
+        let seq = self.position_sequence(s);
+        self.run(
+            &ts,
+            within.as_deref(),
+            seq.as_deref(),
+            budget,
+            None,
+            collector,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Budget, Count, DocID, Facets, Filter, Index, Options, QueryError, Value};
+
+    #[test]
+    fn test_metadata() {
+        let docs = [
+            ("fn parse()", "rust", 100),
+            ("def parse():", "python", 200),
+            ("fn parse_all()", "rust", 300),
+            ("func parse()", "go", 400),
+        ];
+
+        let mut idx = Index::new();
+        for (text, lang, time) in docs {
+            let id = idx.add(text);
+            idx.set_metadata(id, "lang", lang);
+            idx.set_metadata(id, "time", time as i64);
+        }
+
+        assert_eq!(idx.metadata(DocID(1), "lang"), Some(&Value::from("python")));
+        assert_eq!(idx.metadata(DocID(1), "repo"), None);
+
+        let rust = Filter::eq("lang", "rust");
+        assert_eq!(idx.filter_docs(&rust), vec![DocID(0), DocID(2)]);
+        assert_eq!(idx.query_filtered("parse", &rust), vec![DocID(0), DocID(2)]);
+        assert_eq!(idx.query_filtered("parse_", &rust), vec![DocID(2)]);
+        assert_eq!(
+            idx.query_filtered("parse", &Filter::range("time", 150, 400)),
+            vec![DocID(1), DocID(2), DocID(3)]
+        );
+        assert_eq!(
+            idx.query_filtered("parse", &rust.clone().and(Filter::at_least("time", 150))),
+            vec![DocID(2)]
+        );
+        assert_eq!(
+            idx.query_filtered("fn ", &Filter::at_most("time", 50)),
+            vec![]
+        );
+        // a tag never falls in a numeric range
+        assert_eq!(idx.filter_docs(&Filter::at_least("lang", 0)), vec![]);
+        assert_eq!(
+            idx.filter_docs(&Filter::range("lang", "go", "python")),
+            vec![DocID(1), DocID(3)]
+        );
+        assert_eq!(idx.query_filtered("", &rust), vec![DocID(0), DocID(2)]);
+
+        let mut count = Count::default();
+        idx.query_filtered_collect("parse", &Filter::at_least("time", 200), &mut count);
+        assert_eq!(count.0, 3);
+        let mut facets = Facets::new("lang");
+        idx.query_filtered_collect("parse", &Filter::at_least("time", 200), &mut facets);
+        assert_eq!(facets.top(10).len(), 3);
+        let budget = Budget::with_deadline(std::time::Instant::now());
+        match idx.query_filtered_with_budget("parse", &rust, &budget) {
+            Err(QueryError::Timeout(partial)) => assert!(partial.contains(&DocID(2))),
+            got => panic!("expected timeout, got {:?}", got),
+        }
+
+        idx.set_metadata(DocID(0), "lang", "go");
+        assert_eq!(idx.filter_docs(&rust), vec![DocID(2)]);
+        assert_eq!(
+            idx.filter_docs(&Filter::eq("lang", "go").and(Filter::at_most("time", 100))),
+            vec![DocID(0)]
+        );
+
+        // positions are checked during the evaluation
+        let mut idx = Index::with_options(Options {
+            positions: true,
+            ..Options::default()
+        });
+        for d in ["abcxbcd", "xxabcdxx", "bcdabc", "abcdabcd"] {
+            let id = idx.add(d);
+            idx.set_metadata(id, "len", d.len() as i64);
+        }
+        assert_eq!(
+            idx.query_filtered("abcd", &Filter::at_least("len", 7)),
+            vec![DocID(1), DocID(3)]
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use crate::highlight::find_all;
+use crate::{intersect2, DocID, Index, Unit};
+
+impl Index {
+    // QueryNear returns the documents in which a and b occur at most
+    // max_distance bytes apart (code points in a code point index), counted
+    // from the end of one to the start of the other; overlapping occurrences
+    // are 0 apart. When ordered is set, a must start no later than b.
+    //
+    // Occurrences are found from the positional postings, or in the stored
+    // text when the index has no positions. Documents with neither are
+    // returned unverified, unless they were deleted.
+    pub fn query_near(&self, a: &str, b: &str, max_distance: usize, ordered: bool) -> Vec<DocID> {
+        let mut docs = self.query(a);
+        intersect2(&mut docs, &self.query(b));
+
+        let (a, b) = (self.normalize(a), self.normalize(b));
+        let (seq_a, seq_b) = if self.options.positions {
+            (self.analyzer.sequence(&a), self.analyzer.sequence(&b))
+        } else {
+            (None, None)
+        };
+
+        let units = |s: &str| match self.analyzer.unit() {
+            Unit::Byte => s.len() as u32,
+            Unit::Char => s.chars().count() as u32,
+        };
+        let (len_a, len_b) = (units(&a), units(&b));
+        // no two offsets are further apart than u32::MAX
+        let distance = max_distance.min(u32::MAX as usize) as u32;
+
+        docs.into_iter()
+            .filter(|&id| {
+                let from_positions = match (&seq_a, &seq_b) {
+                    (Some(sa), Some(sb)) => self
+                        .sequence_starts(id, sa)
+                        .zip(self.sequence_starts(id, sb)),
+                    _ => None,
+                };
+                let (starts_a, starts_b) = match from_positions {
+                    Some(starts) => starts,
+                    None => match self.document(id) {
+                        None => return !self.is_deleted(id),
+                        Some(doc) => {
+                            let doc = self.normalize(doc);
+                            (self.find_all(&doc, &a), self.find_all(&doc, &b))
+                        }
+                    },
+                };
+                is_near(&starts_a, len_a, &starts_b, len_b, distance, ordered)
+            })
+            .collect()
+    }
+
+    // FindAll returns the start of every occurrence of q in doc, overlapping
+    // ones included, in the units the analyzer counts
+    fn find_all(&self, doc: &str, q: &str) -> Vec<u32> {
+        find_all(doc, q)
+            .into_iter()
+            .map(|at| match self.analyzer.unit() {
+                Unit::Byte => at as u32,
+                Unit::Char => doc[..at].chars().count() as u32,
+            })
+            .collect()
+    }
+}
+
+// IsNear reports whether an occurrence starting in a (of length len_a) is
+// within distance of one starting in b; both must be sorted
+fn is_near(a: &[u32], len_a: u32, b: &[u32], len_b: u32, distance: u32, ordered: bool) -> bool {
+    a.iter().any(|&start| {
+        let lo = if ordered {
+            start
+        } else {
+            start.saturating_sub(len_b.saturating_add(distance))
+        };
+        let hi = start.saturating_add(len_a).saturating_add(distance);
+        let i = b.partition_point(|&x| x < lo);
+        b.get(i).is_some_and(|&x| x <= hi)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DocID, Index, Options};
+
+    #[test]
+    fn test_query_near() {
+        let docs = vec![
+            "error: connection timeout",
+            "timeout waiting for lock, then error",
+            "error in parser; the request completed long before any timeout",
+            "errors",
+        ];
+
+        for options in [
+            Options {
+                positions: true,
+                ..Options::default()
+            },
+            Options {
+                store_documents: true,
+                ..Options::default()
+            },
+        ] {
+            let mut idx = Index::with_options(options);
+            for d in docs.iter() {
+                idx.add(d);
+            }
+
+            assert_eq!(
+                idx.query_near("error", "timeout", 25, false),
+                vec![DocID(0), DocID(1)]
+            );
+            assert_eq!(idx.query_near("error", "timeout", 25, true), vec![DocID(0)]);
+            assert_eq!(idx.query_near("timeout", "error", 25, true), vec![DocID(1)]);
+            assert_eq!(idx.query_near("error", "timeout", 60, true).len(), 2);
+            assert_eq!(idx.query_near("err", "rors", 0, true), vec![DocID(3)]);
+            assert_eq!(
+                idx.query_near("error", "timeout", usize::MAX, false).len(),
+                3
+            );
+            assert_eq!(
+                idx.query_near("error", "timeout", u32::MAX as usize + 1, true)
+                    .len(),
+                2
+            );
+
+            // words too short for a gram match every document, deleted
+            // ones included
+            idx.delete(docs[0], DocID(0));
+            assert!(!idx
+                .query_near("e", "t", usize::MAX, false)
+                .contains(&DocID(0)));
+        }
+
+        // nothing to verify against
+        let idx = Index::new_with_documents(docs);
+        assert_eq!(idx.query_near("error", "timeout", 0, true).len(), 3);
+    }
+}
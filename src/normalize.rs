@@ -0,0 +1,253 @@
+use std::borrow::Cow;
+
+use crate::tables::{
+    COMBINING_CLASSES, COMPOSITIONS, DECOMPOSITIONS, MARK_DECOMPOSITIONS, SINGLETONS,
+};
+
+/// Normalization selects the Unicode normalization form applied to
+/// documents and queries before trigram extraction.
+///
+/// The decomposition tables ship with the crate and cover the Latin, Greek
+/// and Cyrillic blocks plus Hangul syllables; other characters pass through
+/// unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Normalization {
+    #[default]
+    None,
+    /// canonical composition
+    Nfc,
+    /// canonical decomposition
+    Nfd,
+}
+
+// Hangul syllables decompose algorithmically
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+// letters with a diacritic that have no canonical decomposition
+const STROKES: &[(char, char)] = &[
+    ('Ð', 'D'),
+    ('Ø', 'O'),
+    ('ð', 'd'),
+    ('ø', 'o'),
+    ('Đ', 'D'),
+    ('đ', 'd'),
+    ('Ħ', 'H'),
+    ('ħ', 'h'),
+    ('ı', 'i'),
+    ('Ł', 'L'),
+    ('ł', 'l'),
+    ('Ŧ', 'T'),
+    ('ŧ', 't'),
+];
+
+fn combining_class(c: char) -> u8 {
+    match COMBINING_CLASSES.binary_search_by_key(&c, |&(m, _)| m) {
+        Ok(i) => COMBINING_CLASSES[i].1,
+        Err(_) => 0,
+    }
+}
+
+fn decompose_char(c: char, out: &mut Vec<char>) {
+    let cp = c as u32;
+    if (S_BASE..S_BASE + S_COUNT).contains(&cp) {
+        let s = cp - S_BASE;
+        out.push(char::from_u32(L_BASE + s / N_COUNT).unwrap());
+        out.push(char::from_u32(V_BASE + (s % N_COUNT) / T_COUNT).unwrap());
+        if !s.is_multiple_of(T_COUNT) {
+            out.push(char::from_u32(T_BASE + s % T_COUNT).unwrap());
+        }
+        return;
+    }
+
+    if let Ok(i) = SINGLETONS.binary_search_by_key(&c, |&(composed, _)| composed) {
+        decompose_char(SINGLETONS[i].1, out);
+        return;
+    }
+
+    for table in [DECOMPOSITIONS, MARK_DECOMPOSITIONS] {
+        if let Ok(i) = table.binary_search_by_key(&c, |&(composed, _, _)| composed) {
+            let (_, base, mark) = table[i];
+            decompose_char(base, out);
+            out.push(mark);
+            return;
+        }
+    }
+    out.push(c);
+}
+
+fn compose_pair(a: char, b: char) -> Option<char> {
+    let (a32, b32) = (a as u32, b as u32);
+
+    if (L_BASE..L_BASE + L_COUNT).contains(&a32) && (V_BASE..V_BASE + V_COUNT).contains(&b32) {
+        let s = S_BASE + ((a32 - L_BASE) * V_COUNT + (b32 - V_BASE)) * T_COUNT;
+        return char::from_u32(s);
+    }
+    if (S_BASE..S_BASE + S_COUNT).contains(&a32)
+        && (a32 - S_BASE).is_multiple_of(T_COUNT)
+        && (T_BASE + 1..T_BASE + T_COUNT).contains(&b32)
+    {
+        return char::from_u32(a32 + (b32 - T_BASE));
+    }
+
+    match COMPOSITIONS.binary_search_by_key(&(a, b), |&(base, mark, _)| (base, mark)) {
+        Ok(i) => Some(COMPOSITIONS[i].2),
+        Err(_) => None,
+    }
+}
+
+// Decompose returns the canonical decomposition of s with its combining
+// marks in canonical order
+fn decompose(s: &str) -> Vec<char> {
+    let mut out = Vec::<char>::with_capacity(s.len());
+    for c in s.chars() {
+        decompose_char(c, &mut out);
+    }
+
+    // sort each run of combining marks by class, keeping equal classes in order
+    let mut i = 0;
+    while i < out.len() {
+        if combining_class(out[i]) == 0 {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < out.len() && combining_class(out[i]) != 0 {
+            i += 1;
+        }
+        out[start..i].sort_by_key(|&c| combining_class(c));
+    }
+
+    out
+}
+
+// Compose applies canonical composition to decomposed text
+fn compose(chars: &[char]) -> String {
+    let mut out = Vec::<char>::with_capacity(chars.len());
+    let mut starter: Option<usize> = None;
+    let mut last_class = 0u8;
+
+    for &c in chars {
+        let class = combining_class(c);
+
+        if let Some(si) = starter {
+            // c may combine with the starter unless blocked by a mark of the
+            // same or a higher class in between
+            let blocked = out.len() > si + 1 && (last_class == 0 || last_class >= class);
+            if !blocked {
+                if let Some(composed) = compose_pair(out[si], c) {
+                    out[si] = composed;
+                    continue;
+                }
+            }
+        }
+
+        if class == 0 {
+            starter = Some(out.len());
+        }
+        last_class = class;
+        out.push(c);
+    }
+
+    out.into_iter().collect()
+}
+
+impl Normalization {
+    pub fn apply<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        if s.is_ascii() {
+            return Cow::Borrowed(s);
+        }
+
+        match self {
+            Normalization::None => Cow::Borrowed(s),
+            Normalization::Nfc => Cow::Owned(compose(&decompose(s))),
+            Normalization::Nfd => Cow::Owned(decompose(s).into_iter().collect()),
+        }
+    }
+}
+
+// StripAccents removes diacritics from s, leaving its letters in the
+// requested normalization form
+pub(crate) fn strip_accents(s: &str, form: Normalization) -> Cow<'_, str> {
+    if s.is_ascii() {
+        return Cow::Borrowed(s);
+    }
+
+    let mut chars = decompose(s);
+    chars.retain(|&c| combining_class(c) == 0);
+    for c in chars.iter_mut() {
+        if let Ok(i) = STROKES.binary_search_by_key(c, |&(s, _)| s) {
+            *c = STROKES[i].1;
+        }
+    }
+
+    match form {
+        Normalization::Nfd => Cow::Owned(chars.into_iter().collect()),
+        // Hangul may still need composing
+        _ => Cow::Owned(compose(&chars)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strip_accents;
+    use crate::{DocID, Index, Normalization, Options, Standard};
+
+    #[test]
+    fn test_normalization() {
+        let nfc = "caf\u{e9} \u{1ea5} \u{d55c}";
+        let nfd = "cafe\u{301} a\u{302}\u{301} \u{1112}\u{1161}\u{11ab}";
+
+        assert_eq!(Normalization::Nfd.apply(nfc), nfd);
+        assert_eq!(Normalization::Nfc.apply(nfd), nfc);
+        assert_eq!(Normalization::Nfc.apply(nfc), nfc);
+        // marks are reordered by class: dot below (220) before acute (230)
+        assert_eq!(
+            Normalization::Nfd.apply("e\u{301}\u{323}"),
+            "e\u{323}\u{301}"
+        );
+        assert_eq!(
+            Normalization::Nfc.apply("e\u{301}\u{323}"),
+            "\u{1eb9}\u{301}"
+        );
+
+        // oxia forms are singletons of the tonos forms
+        assert_eq!(
+            Normalization::Nfc.apply("\u{1f71}\u{1ffb}"),
+            "\u{3ac}\u{38f}"
+        );
+        assert_eq!(Normalization::Nfd.apply("\u{1f71}"), "\u{3b1}\u{301}");
+        assert_eq!(Normalization::Nfd.apply("\u{344}"), "\u{308}\u{301}");
+        assert_eq!(Normalization::Nfc.apply("\u{3b9}\u{344}"), "\u{390}");
+
+        assert_eq!(strip_accents(nfd, Normalization::Nfc), "cafe a \u{d55c}");
+        assert_eq!(
+            strip_accents("Łódź, Ørsted", Normalization::None),
+            "Lodz, Orsted"
+        );
+
+        let analyzer = Standard {
+            normalization: Normalization::Nfc,
+            strip_accents: true,
+            ..Standard::default()
+        };
+        let mut idx = Index::with_analyzer(analyzer, Options::default());
+        idx.add("café crème");
+        idx.add("cafe\u{301} noir");
+        idx.add("caffè");
+
+        assert_eq!(idx.query("cafe"), vec![DocID(0), DocID(1)]);
+        assert_eq!(idx.query("café"), vec![DocID(0), DocID(1)]);
+        assert_eq!(idx.query("creme"), vec![DocID(0)]);
+
+        idx.delete("cafe\u{301} noir", DocID(1));
+        assert_eq!(idx.query("café"), vec![DocID(0)]);
+    }
+}
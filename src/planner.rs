@@ -0,0 +1,137 @@
+use crate::{Index, Posting, TermFrequency, T};
+
+/// Planner holds the knobs that decide which of a query's trigrams are
+/// intersected, and when intersection stops.
+///
+/// Query results are candidates that still need verifying, so intersecting
+/// fewer trigrams only widens the result; it never drops a real match.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Planner {
+    /// Intersect at most this many of the rarest trigrams; 0 means all of them.
+    pub max_trigrams: usize,
+    /// Stop intersecting once no more than this many candidates remain.
+    pub verify_threshold: usize,
+    /// Skip trigrams whose posting list holds more than this fraction of all
+    /// documents. The rarest trigram is always used.
+    pub max_posting_ratio: f64,
+}
+
+impl Default for Planner {
+    fn default() -> Planner {
+        Planner::exhaustive()
+    }
+}
+
+impl Planner {
+    // Exhaustive intersects every trigram of the query
+    pub fn exhaustive() -> Planner {
+        Planner {
+            max_trigrams: 0,
+            verify_threshold: 0,
+            max_posting_ratio: 1.0,
+        }
+    }
+
+    // Selective intersects only a few rare trigrams and leaves small
+    // candidate sets to the caller's verification
+    pub fn selective() -> Planner {
+        Planner {
+            max_trigrams: 4,
+            verify_threshold: 32,
+            max_posting_ratio: 0.5,
+        }
+    }
+}
+
+pub(crate) enum Selection {
+    // some trigram has no posting list, so nothing can match
+    Nothing,
+    // every trigram was pruned, so anything can match
+    Everything,
+    // the trigrams to intersect, rarest first
+    Trigrams(Vec<T>),
+}
+
+impl Index {
+    pub fn planner(&self) -> &Planner {
+        &self.planner
+    }
+
+    pub fn set_planner(&mut self, planner: Planner) {
+        self.planner = planner;
+    }
+
+    // SelectTrigrams orders the query trigrams by posting length and keeps
+    // the subset the planner considers worth intersecting
+    pub(crate) fn select_trigrams(&self, trigrams: &[T]) -> Selection {
+        let mut unique = trigrams.to_vec();
+        unique.sort_unstable();
+        unique.dedup();
+
+        let mut freqs = Vec::<TermFrequency>::with_capacity(unique.len());
+        for t in unique {
+            let freq = match self.postings.get(&t) {
+                None => return Selection::Nothing,
+                Some(Posting::Pruned) => continue,
+                Some(Posting::List(l)) => l.len(),
+            };
+            freqs.push(TermFrequency { t, freq });
+        }
+
+        if freqs.is_empty() {
+            return Selection::Everything;
+        }
+
+        freqs.sort();
+
+        let max_freq = self.planner.max_posting_ratio * self.get_all_docs().len() as f64;
+        let mut ts = Vec::<T>::with_capacity(freqs.len());
+
+        for tf in freqs.iter() {
+            if !ts.is_empty() {
+                if self.planner.max_trigrams > 0 && ts.len() >= self.planner.max_trigrams {
+                    break;
+                }
+                // the rest are at least as common as this one
+                if tf.freq as f64 > max_freq {
+                    break;
+                }
+            }
+            ts.push(tf.t);
+        }
+
+        Selection::Trigrams(ts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DocID, Index, Planner};
+
+    #[test]
+    fn test_planner() {
+        let docs = vec!["foobar", "foobaz", "fooqux", "barbaz", "foobarbaz"];
+        let mut idx = Index::new_with_documents(docs);
+
+        assert_eq!(idx.query("foobarb"), vec![DocID(4)]);
+
+        idx.set_planner(Planner {
+            max_trigrams: 1,
+            verify_threshold: 0,
+            max_posting_ratio: 1.0,
+        });
+        // only the rarest trigram, "arb", is intersected, which lets
+        // "barbaz" through to verification
+        assert_eq!(idx.query("foobarb"), vec![DocID(3), DocID(4)]);
+
+        idx.set_planner(Planner::exhaustive());
+        assert_eq!(idx.query("foobaz"), vec![DocID(1), DocID(4)]);
+        idx.set_planner(Planner {
+            max_trigrams: 0,
+            verify_threshold: 3,
+            max_posting_ratio: 1.0,
+        });
+        // the first posting list is already small enough to verify
+        assert_eq!(idx.query("foobaz"), vec![DocID(1), DocID(3), DocID(4)]);
+    }
+}
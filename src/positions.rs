@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use crate::{DocID, Index, Posting, T};
+
+impl Index {
+    // InsertPositions records the position of every gram of document id,
+    // before its postings are added
+    pub(crate) fn insert_positions(&mut self, ts: &[T], id: DocID) {
+        let mut offsets = HashMap::<T, Vec<u32>>::new();
+        for (pos, t) in ts.iter().enumerate() {
+            offsets.entry(*t).or_default().push(pos as u32);
+        }
+
+        for (t, mut offsets) in offsets {
+            // keep in step with insert_trigrams, which adds id to the list
+            // unless it is pruned or already ends with id
+            let again = match self.postings.get(&t) {
+                Some(Posting::Pruned) => continue,
+                Some(Posting::List(l)) => l.last() == Some(&id),
+                None => false,
+            };
+
+            let p = self.positions.get_or_default(t);
+            match p.last_mut() {
+                Some(last) if again => {
+                    last.append(&mut offsets);
+                    last.sort_unstable();
+                }
+                _ => p.push(offsets),
+            }
+        }
+    }
+
+    // Offsets returns where t occurs in document id. The outer None means t
+    // is pruned, so it may be anywhere.
+    fn offsets(&self, t: &T, id: DocID) -> Option<&[u32]> {
+        let list = match self.postings.get(t) {
+            Some(Posting::Pruned) => return None,
+            Some(Posting::List(l)) => l,
+            None => return Some(&[]),
+        };
+
+        match (list.binary_search(&id), self.positions.get(t)) {
+            (Ok(i), Some(p)) => Some(&p[i]),
+            _ => Some(&[]),
+        }
+    }
+
+    // SequenceStarts returns the positions in document id at which the grams
+    // of seq occur consecutively, or None if every one of them is pruned
+    pub(crate) fn sequence_starts(&self, id: DocID, seq: &[T]) -> Option<Vec<u32>> {
+        let mut lists = Vec::<(u32, &[u32])>::with_capacity(seq.len());
+        for (k, t) in seq.iter().enumerate() {
+            match self.offsets(t, id) {
+                None => continue,
+                Some(offsets) => lists.push((k as u32, offsets)),
+            }
+        }
+
+        // anchor on the gram with the fewest occurrences
+        lists.sort_by_key(|(_, offsets)| offsets.len());
+        let (k0, first) = *lists.first()?;
+
+        let starts = first
+            .iter()
+            .filter(|&&p| p >= k0)
+            .map(|&p| p - k0)
+            .filter(|&start| {
+                lists[1..]
+                    .iter()
+                    .all(|(k, offsets)| offsets.binary_search(&(start + k)).is_ok())
+            })
+            .collect();
+        Some(starts)
+    }
+
+    // ExactSequenceStarts is like SequenceStarts but only returns starts
+    // backed by every gram of seq, so none if any of them is pruned
+    pub(crate) fn exact_sequence_starts(&self, id: DocID, seq: &[T]) -> Vec<u32> {
+        let pruned = seq
+            .iter()
+            .any(|t| matches!(self.postings.get(t), Some(Posting::Pruned)));
+        if pruned {
+            return Vec::new();
+        }
+        self.sequence_starts(id, seq).unwrap_or_default()
+    }
+
+    // ContainsSequence reports whether the grams of seq occur in document id
+    // at consecutive positions
+    pub(crate) fn contains_sequence(&self, id: DocID, seq: &[T]) -> bool {
+        match self.sequence_starts(id, seq) {
+            None => true,
+            Some(starts) => !starts.is_empty(),
+        }
+    }
+
+    // PositionSequence returns the grams of query s to check for adjacency,
+    // if the index records positions and s has more than one
+    pub(crate) fn position_sequence(&self, s: &str) -> Option<Vec<T>> {
+        if !self.options.positions {
+            return None;
+        }
+
+        match self.analyzer.sequence(&self.normalize(s)) {
+            Some(seq) if seq.len() > 1 => Some(seq),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DocID, Index, Options, Standard};
+
+    #[test]
+    fn test_positions() {
+        let docs = vec!["abcxbcd", "xxabcdxx", "bcdabc", "abcdabcd"];
+
+        let idx = Index::new_with_documents(docs.clone());
+        assert_eq!(
+            idx.query("abcd"),
+            vec![DocID(0), DocID(1), DocID(2), DocID(3)]
+        );
+
+        let mut idx = Index::with_options(Options {
+            positions: true,
+            ..Options::default()
+        });
+        for d in docs.iter() {
+            idx.add(d);
+        }
+        assert_eq!(idx.query("abcd"), vec![DocID(1), DocID(3)]);
+        assert_eq!(idx.query("dabc"), vec![DocID(2), DocID(3)]);
+        assert_eq!(idx.query("bcdab"), vec![DocID(2), DocID(3)]);
+        assert_eq!(idx.query("xbc"), vec![DocID(0)]);
+
+        idx.delete("xxabcdxx", DocID(1));
+        assert_eq!(idx.query("abcd"), vec![DocID(3)]);
+
+        // a pruned gram can't be placed, so it matches anywhere
+        idx.prune(0.5);
+        assert_eq!(idx.query("xbcd"), vec![DocID(0)]);
+        assert_eq!(idx.query("cdab"), vec![DocID(2), DocID(3)]);
+
+        let analyzer = Standard {
+            pad: true,
+            ..Standard::default()
+        };
+        let mut idx = Index::with_analyzer(
+            analyzer,
+            Options {
+                positions: true,
+                ..Options::default()
+            },
+        );
+        for d in docs.iter() {
+            idx.add(d);
+        }
+        assert_eq!(idx.query("abcd"), vec![DocID(1), DocID(3)]);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{Analyzer, DocID, Index, Options, Standard};
+
+impl Index {
+    // SetPrior sets the static score of document id, such as its popularity
+    // or recency, independent of any query
+    pub fn set_prior(&mut self, id: DocID, score: f32) {
+        if self.priors.len() <= id.as_usize() {
+            self.priors.resize(id.as_usize() + 1, 0.0);
+        }
+        self.priors[id.as_usize()] = score;
+    }
+
+    // Prior returns the static score of document id, 0 if none was set
+    pub fn prior(&self, id: DocID) -> f32 {
+        self.priors.get(id.as_usize()).copied().unwrap_or(0.0)
+    }
+
+    // AddScoredDocuments adds docs in descending order of their static
+    // score, so lower DocIDs are better documents, and returns the DocID of
+    // each in the order given. Documents with equal scores keep their order.
+    //
+    // Queries return documents in DocID order, so a collector that stops
+    // early, such as FirstN, gets the best documents that match. Only the
+    // batch is ordered: its documents follow any already in the index, and
+    // documents added later follow it whatever their prior, so an index
+    // should be built from one batch.
+    pub fn add_scored_documents(&mut self, docs: &[(&str, f32)]) -> Vec<DocID> {
+        let mut order: Vec<usize> = (0..docs.len()).collect();
+        order.sort_by(|&a, &b| docs[b].1.total_cmp(&docs[a].1));
+
+        let mut ids = vec![DocID(0); docs.len()];
+        for i in order {
+            let (d, score) = docs[i];
+            let id = self.add(d);
+            self.set_prior(id, score);
+            ids[i] = id;
+        }
+        ids
+    }
+
+    // NewWithScoredDocuments is like NewWithDocuments but assigns DocIDs in
+    // descending order of score; see add_scored_documents
+    pub fn new_with_scored_documents(docs: &[(&str, f32)]) -> (Index, Vec<DocID>) {
+        Index::with_scored_documents(Standard::default(), Options::default(), docs)
+    }
+
+    // WithScoredDocuments is like NewWithScoredDocuments but configures the
+    // index with analyzer and options, as with_analyzer does
+    pub fn with_scored_documents<A: Analyzer + 'static>(
+        analyzer: A,
+        options: Options,
+        docs: &[(&str, f32)],
+    ) -> (Index, Vec<DocID>) {
+        let mut idx = Index::with_analyzer(analyzer, options);
+        let ids = idx.add_scored_documents(docs);
+        (idx, ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CaseFold, DocID, FirstN, Index, Options, Standard, TopScored};
+
+    #[test]
+    fn test_priors() {
+        let docs = [
+            ("src/old_parser.rs", 0.1),
+            ("src/parser.rs", 0.9),
+            ("src/lexer.rs", 0.5),
+            ("tests/parser_test.rs", 0.5),
+        ];
+
+        let (idx, ids) = Index::new_with_scored_documents(&docs);
+        assert_eq!(ids, vec![DocID(3), DocID(0), DocID(1), DocID(2)]);
+        assert_eq!(idx.prior(ids[1]), 0.9);
+        assert_eq!(idx.prior(DocID(7)), 0.0);
+        assert_eq!(idx.query("").len(), 4);
+
+        let mut first = FirstN::new(2);
+        idx.query_collect("parser", &mut first);
+        assert_eq!(first.docs, vec![ids[1], ids[3]]);
+
+        let analyzer = Standard {
+            case_fold: CaseFold::Ascii,
+            ..Standard::default()
+        };
+        let options = Options {
+            store_documents: true,
+            ..Options::default()
+        };
+        let (idx, ids) = Index::with_scored_documents(analyzer, options, &docs);
+        assert_eq!(idx.query("PARSER"), vec![ids[1], ids[3], ids[0]]);
+        assert_eq!(idx.document(ids[1]), Some("src/parser.rs"));
+
+        let mut idx = Index::new();
+        for (d, _) in docs.iter() {
+            idx.add(d);
+        }
+        idx.set_prior(DocID(3), 2.0);
+        let mut top = TopScored::new(1, |idx: &Index, id| idx.prior(id));
+        idx.query_collect("parser", &mut top);
+        assert_eq!(top.into_sorted(), vec![(DocID(3), 2.0)]);
+    }
+}
@@ -0,0 +1,150 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+use crate::merge::CountMerge;
+use crate::{DocID, Index, Posting};
+
+/// Similarity selects how the trigrams shared by a query and a document are
+/// turned into a score
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Similarity {
+    /// the number of query trigrams found in the document
+    Count,
+    /// shared / (query + document - shared), as pg_trgm's similarity()
+    Jaccard,
+    /// 2 * shared / (query + document)
+    Dice,
+}
+
+impl Similarity {
+    pub(crate) fn score(self, shared: usize, query: usize, doc: usize) -> f32 {
+        // a stale document length must not push scores above 1
+        let doc = doc.max(shared);
+        match self {
+            Similarity::Count => shared as f32,
+            Similarity::Jaccard => shared as f32 / (query + doc - shared) as f32,
+            Similarity::Dice => 2.0 * shared as f32 / (query + doc) as f32,
+        }
+    }
+}
+
+struct Scored {
+    doc: DocID,
+    score: f32,
+}
+
+// higher scores rank first; ties go to the lower DocID
+impl Ord for Scored {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score
+            .total_cmp(&other.score)
+            .then_with(|| other.doc.cmp(&self.doc))
+    }
+}
+
+impl PartialOrd for Scored {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Scored {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Scored {}
+
+// TopK keeps the k best scored documents seen so far in a min-heap
+pub(crate) struct TopK {
+    k: usize,
+    heap: BinaryHeap<Reverse<Scored>>,
+}
+
+impl TopK {
+    pub(crate) fn new(k: usize) -> TopK {
+        TopK {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub(crate) fn push(&mut self, doc: DocID, score: f32) {
+        if self.k == 0 {
+            return;
+        }
+
+        let s = Scored { doc, score };
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(s));
+        } else if let Some(Reverse(worst)) = self.heap.peek() {
+            if s > *worst {
+                self.heap.pop();
+                self.heap.push(Reverse(s));
+            }
+        }
+    }
+
+    // IntoSorted returns the documents best first
+    pub(crate) fn into_sorted(self) -> Vec<(DocID, f32)> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(s)| (s.doc, s.score))
+            .collect()
+    }
+}
+
+impl Index {
+    // Similar returns the k documents most similar to s by Jaccard
+    // similarity of their trigram sets, best first
+    pub fn similar(&self, s: &str, k: usize) -> Vec<(DocID, f32)> {
+        self.similar_by(s, k, Similarity::Jaccard)
+    }
+
+    // SimilarBy is like Similar but scores with the given measure. Pruned
+    // trigrams count as not shared, since their documents are unknown.
+    pub fn similar_by(&self, s: &str, k: usize, measure: Similarity) -> Vec<(DocID, f32)> {
+        let ts = self.extract_query(s);
+        if ts.is_empty() || k == 0 {
+            return Vec::new();
+        }
+
+        let mut lists = Vec::with_capacity(ts.len());
+        for t in ts.iter() {
+            if let Some(Posting::List(l)) = self.postings.get(t) {
+                lists.push(l.as_slice());
+            }
+        }
+
+        let mut top = TopK::new(k);
+        for (doc, shared) in CountMerge::new(lists) {
+            let len = self.lengths.get(doc.as_usize()).copied().unwrap_or(0);
+            top.push(doc, measure.score(shared, ts.len(), len as usize));
+        }
+
+        top.into_sorted()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DocID, Index, Similarity};
+
+    #[test]
+    fn test_similar() {
+        let docs = vec!["postgres", "postgresql", "mysql", "sqlite", "postgis"];
+        let idx = Index::new_with_documents(docs);
+
+        // "postgers" shares pos, ost and stg with postgres, postgresql and
+        // postgis, so the shortest of them wins
+        let got = idx.similar("postgers", 2);
+        assert_eq!(got, vec![(DocID(4), 3.0 / 8.0), (DocID(0), 3.0 / 9.0)]);
+
+        let got = idx.similar_by("sql", 5, Similarity::Count);
+        assert_eq!(got, vec![(DocID(1), 1.0), (DocID(2), 1.0), (DocID(3), 1.0)]);
+
+        assert!(idx.similar("zzz", 3).is_empty());
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{Analyzer, T};
+
+/// SparseGrams is an analyzer that extracts variable-length sparse n-grams
+/// of bytes, which are far more selective than trigrams on source code.
+///
+/// Every pair of adjacent bytes gets a deterministic weight. A substring of
+/// at least three bytes is a sparse gram when the weight of its first pair
+/// is at least, and that of its last pair greater than, the weight of every
+/// pair inside it; whether a substring qualifies depends on its bytes alone.
+/// Documents index all their sparse grams, every trigram among them, and
+/// queries look up only the longest grams that together cover the query.
+#[derive(Debug, Clone)]
+pub struct SparseGrams {
+    /// Grams longer than this many bytes are not indexed; those longer than
+    /// MAX_BYTE_GRAM are hashed. At least 3.
+    pub max_len: usize,
+}
+
+impl Default for SparseGrams {
+    fn default() -> SparseGrams {
+        SparseGrams { max_len: 32 }
+    }
+}
+
+// PairWeight is the weight of the byte pair (a, b). Code search engines
+// weight pairs by their rarity in a sample corpus; a hash needs no training
+// and still spreads the gram boundaries evenly.
+fn pair_weight(a: u8, b: u8) -> u32 {
+    let mut x = (a as u32) << 8 | b as u32;
+    x = x.wrapping_mul(0x9e3779b1);
+    x ^= x >> 16;
+    x = x.wrapping_mul(0x85ebca6b);
+    x ^ x >> 13
+}
+
+// SparseRanges calls emit with the byte range of every sparse gram of b no
+// longer than max_len. The pairs are kept on a stack of non-increasing
+// weight, so each gram is found in amortized constant time and there are
+// fewer than 2*len(b) of them.
+fn sparse_ranges(b: &[u8], max_len: usize, mut emit: impl FnMut(usize, usize)) {
+    let w: Vec<u32> = b.windows(2).map(|p| pair_weight(p[0], p[1])).collect();
+    let mut stack = Vec::<usize>::new();
+
+    for j in 0..w.len() {
+        while let Some(&i) = stack.last() {
+            // pairs i through j span bytes i..j+2
+            if j + 2 - i <= max_len {
+                emit(i, j + 2);
+            }
+            if w[i] < w[j] {
+                stack.pop();
+            } else {
+                break;
+            }
+        }
+        stack.push(j);
+    }
+}
+
+impl Analyzer for SparseGrams {
+    fn document(&self, s: &str, ts: &mut Vec<T>) {
+        let b = s.as_bytes();
+        sparse_ranges(b, self.max_len.max(3), |i, j| {
+            ts.push(T::from_long_bytes(&b[i..j]))
+        });
+    }
+
+    fn query(&self, s: &str) -> Vec<T> {
+        let b = s.as_bytes();
+        let mut ranges = Vec::<(usize, usize)>::new();
+        sparse_ranges(b, self.max_len.max(3), |i, j| ranges.push((i, j)));
+
+        // keep the grams not contained in another; since every trigram is a
+        // sparse gram, these still cover every trigram of the query
+        ranges.sort_by_key(|&(i, j)| (i, std::cmp::Reverse(j)));
+        let mut end = 0;
+        let mut ts = Vec::<T>::new();
+        for (i, j) in ranges {
+            if j > end {
+                end = j;
+                let t = T::from_long_bytes(&b[i..j]);
+                if !ts.contains(&t) {
+                    ts.push(t);
+                }
+            }
+        }
+        ts
+    }
+
+    fn grams_per_edit(&self) -> usize {
+        // an edit destroys the covering grams spanning it, and they start at
+        // distinct bytes no further apart than the longest gram
+        self.max_len.max(3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{extract_trigrams, Analyzer, Index, Options, SparseGrams, T};
+
+    #[test]
+    fn test_sparse_grams() {
+        let docs = vec![
+            "fn main() { println!(\"hello, world\"); }",
+            "let mut count = 0; count += 1;",
+            "print(\"hello\") # python",
+            "for (int i = 0; i < count; i++) printf(\"%d\", i);",
+            "println! println!",
+        ];
+
+        let mut sparse = Index::with_analyzer(SparseGrams::default(), Options::default());
+        for d in docs.iter() {
+            sparse.add(d);
+        }
+        let trigram = Index::new_with_documents(docs.clone());
+
+        // every substring finds its documents, and never more than trigrams do
+        for d in docs.iter() {
+            for i in 0..d.len() {
+                for j in i + 1..=d.len() {
+                    let q = &d[i..j];
+                    let want: Vec<_> = (0..docs.len()).filter(|&k| docs[k].contains(q)).collect();
+                    let got: Vec<_> = sparse.query(q).iter().map(|id| id.as_usize()).collect();
+                    let loose: Vec<_> = trigram.query(q).iter().map(|id| id.as_usize()).collect();
+
+                    assert!(want.iter().all(|k| got.contains(k)), "{:?}", q);
+                    assert!(got.iter().all(|k| loose.contains(k)), "{:?}", q);
+                }
+            }
+        }
+
+        let analyzer = SparseGrams::default();
+        let q = "println!(\"hello, world\")";
+        assert!(analyzer.query(q).len() < extract_trigrams(q).len());
+
+        let long = "abcdefghijklmnopqrstuvwxyz";
+        assert_eq!(T::from_long_bytes(long.as_bytes()).len(), long.len());
+        assert_eq!(
+            format!("{}", T::from_long_bytes(b"abcdefghijklmnop")),
+            "T(#7ef46f6c05086855)"
+        );
+        assert_eq!(T::from_long_bytes(b"abc"), T::from_bytes(b"abc"));
+    }
+}
@@ -0,0 +1,595 @@
+// Derived from the Unicode 14.0.0 character database.
+//
+// Canonical decompositions into a base character and one combining mark,
+// covering the Latin, Greek and Cyrillic blocks. Longer decompositions
+// are reached by decomposing the base again.
+pub(crate) const DECOMPOSITIONS: &[(char, char, char)] = &[
+    ('\u{00c0}', '\u{0041}', '\u{0300}'), ('\u{00c1}', '\u{0041}', '\u{0301}'), ('\u{00c2}', '\u{0041}', '\u{0302}'),
+    ('\u{00c3}', '\u{0041}', '\u{0303}'), ('\u{00c4}', '\u{0041}', '\u{0308}'), ('\u{00c5}', '\u{0041}', '\u{030a}'),
+    ('\u{00c7}', '\u{0043}', '\u{0327}'), ('\u{00c8}', '\u{0045}', '\u{0300}'), ('\u{00c9}', '\u{0045}', '\u{0301}'),
+    ('\u{00ca}', '\u{0045}', '\u{0302}'), ('\u{00cb}', '\u{0045}', '\u{0308}'), ('\u{00cc}', '\u{0049}', '\u{0300}'),
+    ('\u{00cd}', '\u{0049}', '\u{0301}'), ('\u{00ce}', '\u{0049}', '\u{0302}'), ('\u{00cf}', '\u{0049}', '\u{0308}'),
+    ('\u{00d1}', '\u{004e}', '\u{0303}'), ('\u{00d2}', '\u{004f}', '\u{0300}'), ('\u{00d3}', '\u{004f}', '\u{0301}'),
+    ('\u{00d4}', '\u{004f}', '\u{0302}'), ('\u{00d5}', '\u{004f}', '\u{0303}'), ('\u{00d6}', '\u{004f}', '\u{0308}'),
+    ('\u{00d9}', '\u{0055}', '\u{0300}'), ('\u{00da}', '\u{0055}', '\u{0301}'), ('\u{00db}', '\u{0055}', '\u{0302}'),
+    ('\u{00dc}', '\u{0055}', '\u{0308}'), ('\u{00dd}', '\u{0059}', '\u{0301}'), ('\u{00e0}', '\u{0061}', '\u{0300}'),
+    ('\u{00e1}', '\u{0061}', '\u{0301}'), ('\u{00e2}', '\u{0061}', '\u{0302}'), ('\u{00e3}', '\u{0061}', '\u{0303}'),
+    ('\u{00e4}', '\u{0061}', '\u{0308}'), ('\u{00e5}', '\u{0061}', '\u{030a}'), ('\u{00e7}', '\u{0063}', '\u{0327}'),
+    ('\u{00e8}', '\u{0065}', '\u{0300}'), ('\u{00e9}', '\u{0065}', '\u{0301}'), ('\u{00ea}', '\u{0065}', '\u{0302}'),
+    ('\u{00eb}', '\u{0065}', '\u{0308}'), ('\u{00ec}', '\u{0069}', '\u{0300}'), ('\u{00ed}', '\u{0069}', '\u{0301}'),
+    ('\u{00ee}', '\u{0069}', '\u{0302}'), ('\u{00ef}', '\u{0069}', '\u{0308}'), ('\u{00f1}', '\u{006e}', '\u{0303}'),
+    ('\u{00f2}', '\u{006f}', '\u{0300}'), ('\u{00f3}', '\u{006f}', '\u{0301}'), ('\u{00f4}', '\u{006f}', '\u{0302}'),
+    ('\u{00f5}', '\u{006f}', '\u{0303}'), ('\u{00f6}', '\u{006f}', '\u{0308}'), ('\u{00f9}', '\u{0075}', '\u{0300}'),
+    ('\u{00fa}', '\u{0075}', '\u{0301}'), ('\u{00fb}', '\u{0075}', '\u{0302}'), ('\u{00fc}', '\u{0075}', '\u{0308}'),
+    ('\u{00fd}', '\u{0079}', '\u{0301}'), ('\u{00ff}', '\u{0079}', '\u{0308}'), ('\u{0100}', '\u{0041}', '\u{0304}'),
+    ('\u{0101}', '\u{0061}', '\u{0304}'), ('\u{0102}', '\u{0041}', '\u{0306}'), ('\u{0103}', '\u{0061}', '\u{0306}'),
+    ('\u{0104}', '\u{0041}', '\u{0328}'), ('\u{0105}', '\u{0061}', '\u{0328}'), ('\u{0106}', '\u{0043}', '\u{0301}'),
+    ('\u{0107}', '\u{0063}', '\u{0301}'), ('\u{0108}', '\u{0043}', '\u{0302}'), ('\u{0109}', '\u{0063}', '\u{0302}'),
+    ('\u{010a}', '\u{0043}', '\u{0307}'), ('\u{010b}', '\u{0063}', '\u{0307}'), ('\u{010c}', '\u{0043}', '\u{030c}'),
+    ('\u{010d}', '\u{0063}', '\u{030c}'), ('\u{010e}', '\u{0044}', '\u{030c}'), ('\u{010f}', '\u{0064}', '\u{030c}'),
+    ('\u{0112}', '\u{0045}', '\u{0304}'), ('\u{0113}', '\u{0065}', '\u{0304}'), ('\u{0114}', '\u{0045}', '\u{0306}'),
+    ('\u{0115}', '\u{0065}', '\u{0306}'), ('\u{0116}', '\u{0045}', '\u{0307}'), ('\u{0117}', '\u{0065}', '\u{0307}'),
+    ('\u{0118}', '\u{0045}', '\u{0328}'), ('\u{0119}', '\u{0065}', '\u{0328}'), ('\u{011a}', '\u{0045}', '\u{030c}'),
+    ('\u{011b}', '\u{0065}', '\u{030c}'), ('\u{011c}', '\u{0047}', '\u{0302}'), ('\u{011d}', '\u{0067}', '\u{0302}'),
+    ('\u{011e}', '\u{0047}', '\u{0306}'), ('\u{011f}', '\u{0067}', '\u{0306}'), ('\u{0120}', '\u{0047}', '\u{0307}'),
+    ('\u{0121}', '\u{0067}', '\u{0307}'), ('\u{0122}', '\u{0047}', '\u{0327}'), ('\u{0123}', '\u{0067}', '\u{0327}'),
+    ('\u{0124}', '\u{0048}', '\u{0302}'), ('\u{0125}', '\u{0068}', '\u{0302}'), ('\u{0128}', '\u{0049}', '\u{0303}'),
+    ('\u{0129}', '\u{0069}', '\u{0303}'), ('\u{012a}', '\u{0049}', '\u{0304}'), ('\u{012b}', '\u{0069}', '\u{0304}'),
+    ('\u{012c}', '\u{0049}', '\u{0306}'), ('\u{012d}', '\u{0069}', '\u{0306}'), ('\u{012e}', '\u{0049}', '\u{0328}'),
+    ('\u{012f}', '\u{0069}', '\u{0328}'), ('\u{0130}', '\u{0049}', '\u{0307}'), ('\u{0134}', '\u{004a}', '\u{0302}'),
+    ('\u{0135}', '\u{006a}', '\u{0302}'), ('\u{0136}', '\u{004b}', '\u{0327}'), ('\u{0137}', '\u{006b}', '\u{0327}'),
+    ('\u{0139}', '\u{004c}', '\u{0301}'), ('\u{013a}', '\u{006c}', '\u{0301}'), ('\u{013b}', '\u{004c}', '\u{0327}'),
+    ('\u{013c}', '\u{006c}', '\u{0327}'), ('\u{013d}', '\u{004c}', '\u{030c}'), ('\u{013e}', '\u{006c}', '\u{030c}'),
+    ('\u{0143}', '\u{004e}', '\u{0301}'), ('\u{0144}', '\u{006e}', '\u{0301}'), ('\u{0145}', '\u{004e}', '\u{0327}'),
+    ('\u{0146}', '\u{006e}', '\u{0327}'), ('\u{0147}', '\u{004e}', '\u{030c}'), ('\u{0148}', '\u{006e}', '\u{030c}'),
+    ('\u{014c}', '\u{004f}', '\u{0304}'), ('\u{014d}', '\u{006f}', '\u{0304}'), ('\u{014e}', '\u{004f}', '\u{0306}'),
+    ('\u{014f}', '\u{006f}', '\u{0306}'), ('\u{0150}', '\u{004f}', '\u{030b}'), ('\u{0151}', '\u{006f}', '\u{030b}'),
+    ('\u{0154}', '\u{0052}', '\u{0301}'), ('\u{0155}', '\u{0072}', '\u{0301}'), ('\u{0156}', '\u{0052}', '\u{0327}'),
+    ('\u{0157}', '\u{0072}', '\u{0327}'), ('\u{0158}', '\u{0052}', '\u{030c}'), ('\u{0159}', '\u{0072}', '\u{030c}'),
+    ('\u{015a}', '\u{0053}', '\u{0301}'), ('\u{015b}', '\u{0073}', '\u{0301}'), ('\u{015c}', '\u{0053}', '\u{0302}'),
+    ('\u{015d}', '\u{0073}', '\u{0302}'), ('\u{015e}', '\u{0053}', '\u{0327}'), ('\u{015f}', '\u{0073}', '\u{0327}'),
+    ('\u{0160}', '\u{0053}', '\u{030c}'), ('\u{0161}', '\u{0073}', '\u{030c}'), ('\u{0162}', '\u{0054}', '\u{0327}'),
+    ('\u{0163}', '\u{0074}', '\u{0327}'), ('\u{0164}', '\u{0054}', '\u{030c}'), ('\u{0165}', '\u{0074}', '\u{030c}'),
+    ('\u{0168}', '\u{0055}', '\u{0303}'), ('\u{0169}', '\u{0075}', '\u{0303}'), ('\u{016a}', '\u{0055}', '\u{0304}'),
+    ('\u{016b}', '\u{0075}', '\u{0304}'), ('\u{016c}', '\u{0055}', '\u{0306}'), ('\u{016d}', '\u{0075}', '\u{0306}'),
+    ('\u{016e}', '\u{0055}', '\u{030a}'), ('\u{016f}', '\u{0075}', '\u{030a}'), ('\u{0170}', '\u{0055}', '\u{030b}'),
+    ('\u{0171}', '\u{0075}', '\u{030b}'), ('\u{0172}', '\u{0055}', '\u{0328}'), ('\u{0173}', '\u{0075}', '\u{0328}'),
+    ('\u{0174}', '\u{0057}', '\u{0302}'), ('\u{0175}', '\u{0077}', '\u{0302}'), ('\u{0176}', '\u{0059}', '\u{0302}'),
+    ('\u{0177}', '\u{0079}', '\u{0302}'), ('\u{0178}', '\u{0059}', '\u{0308}'), ('\u{0179}', '\u{005a}', '\u{0301}'),
+    ('\u{017a}', '\u{007a}', '\u{0301}'), ('\u{017b}', '\u{005a}', '\u{0307}'), ('\u{017c}', '\u{007a}', '\u{0307}'),
+    ('\u{017d}', '\u{005a}', '\u{030c}'), ('\u{017e}', '\u{007a}', '\u{030c}'), ('\u{01a0}', '\u{004f}', '\u{031b}'),
+    ('\u{01a1}', '\u{006f}', '\u{031b}'), ('\u{01af}', '\u{0055}', '\u{031b}'), ('\u{01b0}', '\u{0075}', '\u{031b}'),
+    ('\u{01cd}', '\u{0041}', '\u{030c}'), ('\u{01ce}', '\u{0061}', '\u{030c}'), ('\u{01cf}', '\u{0049}', '\u{030c}'),
+    ('\u{01d0}', '\u{0069}', '\u{030c}'), ('\u{01d1}', '\u{004f}', '\u{030c}'), ('\u{01d2}', '\u{006f}', '\u{030c}'),
+    ('\u{01d3}', '\u{0055}', '\u{030c}'), ('\u{01d4}', '\u{0075}', '\u{030c}'), ('\u{01d5}', '\u{00dc}', '\u{0304}'),
+    ('\u{01d6}', '\u{00fc}', '\u{0304}'), ('\u{01d7}', '\u{00dc}', '\u{0301}'), ('\u{01d8}', '\u{00fc}', '\u{0301}'),
+    ('\u{01d9}', '\u{00dc}', '\u{030c}'), ('\u{01da}', '\u{00fc}', '\u{030c}'), ('\u{01db}', '\u{00dc}', '\u{0300}'),
+    ('\u{01dc}', '\u{00fc}', '\u{0300}'), ('\u{01de}', '\u{00c4}', '\u{0304}'), ('\u{01df}', '\u{00e4}', '\u{0304}'),
+    ('\u{01e0}', '\u{0226}', '\u{0304}'), ('\u{01e1}', '\u{0227}', '\u{0304}'), ('\u{01e2}', '\u{00c6}', '\u{0304}'),
+    ('\u{01e3}', '\u{00e6}', '\u{0304}'), ('\u{01e6}', '\u{0047}', '\u{030c}'), ('\u{01e7}', '\u{0067}', '\u{030c}'),
+    ('\u{01e8}', '\u{004b}', '\u{030c}'), ('\u{01e9}', '\u{006b}', '\u{030c}'), ('\u{01ea}', '\u{004f}', '\u{0328}'),
+    ('\u{01eb}', '\u{006f}', '\u{0328}'), ('\u{01ec}', '\u{01ea}', '\u{0304}'), ('\u{01ed}', '\u{01eb}', '\u{0304}'),
+    ('\u{01ee}', '\u{01b7}', '\u{030c}'), ('\u{01ef}', '\u{0292}', '\u{030c}'), ('\u{01f0}', '\u{006a}', '\u{030c}'),
+    ('\u{01f4}', '\u{0047}', '\u{0301}'), ('\u{01f5}', '\u{0067}', '\u{0301}'), ('\u{01f8}', '\u{004e}', '\u{0300}'),
+    ('\u{01f9}', '\u{006e}', '\u{0300}'), ('\u{01fa}', '\u{00c5}', '\u{0301}'), ('\u{01fb}', '\u{00e5}', '\u{0301}'),
+    ('\u{01fc}', '\u{00c6}', '\u{0301}'), ('\u{01fd}', '\u{00e6}', '\u{0301}'), ('\u{01fe}', '\u{00d8}', '\u{0301}'),
+    ('\u{01ff}', '\u{00f8}', '\u{0301}'), ('\u{0200}', '\u{0041}', '\u{030f}'), ('\u{0201}', '\u{0061}', '\u{030f}'),
+    ('\u{0202}', '\u{0041}', '\u{0311}'), ('\u{0203}', '\u{0061}', '\u{0311}'), ('\u{0204}', '\u{0045}', '\u{030f}'),
+    ('\u{0205}', '\u{0065}', '\u{030f}'), ('\u{0206}', '\u{0045}', '\u{0311}'), ('\u{0207}', '\u{0065}', '\u{0311}'),
+    ('\u{0208}', '\u{0049}', '\u{030f}'), ('\u{0209}', '\u{0069}', '\u{030f}'), ('\u{020a}', '\u{0049}', '\u{0311}'),
+    ('\u{020b}', '\u{0069}', '\u{0311}'), ('\u{020c}', '\u{004f}', '\u{030f}'), ('\u{020d}', '\u{006f}', '\u{030f}'),
+    ('\u{020e}', '\u{004f}', '\u{0311}'), ('\u{020f}', '\u{006f}', '\u{0311}'), ('\u{0210}', '\u{0052}', '\u{030f}'),
+    ('\u{0211}', '\u{0072}', '\u{030f}'), ('\u{0212}', '\u{0052}', '\u{0311}'), ('\u{0213}', '\u{0072}', '\u{0311}'),
+    ('\u{0214}', '\u{0055}', '\u{030f}'), ('\u{0215}', '\u{0075}', '\u{030f}'), ('\u{0216}', '\u{0055}', '\u{0311}'),
+    ('\u{0217}', '\u{0075}', '\u{0311}'), ('\u{0218}', '\u{0053}', '\u{0326}'), ('\u{0219}', '\u{0073}', '\u{0326}'),
+    ('\u{021a}', '\u{0054}', '\u{0326}'), ('\u{021b}', '\u{0074}', '\u{0326}'), ('\u{021e}', '\u{0048}', '\u{030c}'),
+    ('\u{021f}', '\u{0068}', '\u{030c}'), ('\u{0226}', '\u{0041}', '\u{0307}'), ('\u{0227}', '\u{0061}', '\u{0307}'),
+    ('\u{0228}', '\u{0045}', '\u{0327}'), ('\u{0229}', '\u{0065}', '\u{0327}'), ('\u{022a}', '\u{00d6}', '\u{0304}'),
+    ('\u{022b}', '\u{00f6}', '\u{0304}'), ('\u{022c}', '\u{00d5}', '\u{0304}'), ('\u{022d}', '\u{00f5}', '\u{0304}'),
+    ('\u{022e}', '\u{004f}', '\u{0307}'), ('\u{022f}', '\u{006f}', '\u{0307}'), ('\u{0230}', '\u{022e}', '\u{0304}'),
+    ('\u{0231}', '\u{022f}', '\u{0304}'), ('\u{0232}', '\u{0059}', '\u{0304}'), ('\u{0233}', '\u{0079}', '\u{0304}'),
+    ('\u{0385}', '\u{00a8}', '\u{0301}'), ('\u{0386}', '\u{0391}', '\u{0301}'), ('\u{0388}', '\u{0395}', '\u{0301}'),
+    ('\u{0389}', '\u{0397}', '\u{0301}'), ('\u{038a}', '\u{0399}', '\u{0301}'), ('\u{038c}', '\u{039f}', '\u{0301}'),
+    ('\u{038e}', '\u{03a5}', '\u{0301}'), ('\u{038f}', '\u{03a9}', '\u{0301}'), ('\u{0390}', '\u{03ca}', '\u{0301}'),
+    ('\u{03aa}', '\u{0399}', '\u{0308}'), ('\u{03ab}', '\u{03a5}', '\u{0308}'), ('\u{03ac}', '\u{03b1}', '\u{0301}'),
+    ('\u{03ad}', '\u{03b5}', '\u{0301}'), ('\u{03ae}', '\u{03b7}', '\u{0301}'), ('\u{03af}', '\u{03b9}', '\u{0301}'),
+    ('\u{03b0}', '\u{03cb}', '\u{0301}'), ('\u{03ca}', '\u{03b9}', '\u{0308}'), ('\u{03cb}', '\u{03c5}', '\u{0308}'),
+    ('\u{03cc}', '\u{03bf}', '\u{0301}'), ('\u{03cd}', '\u{03c5}', '\u{0301}'), ('\u{03ce}', '\u{03c9}', '\u{0301}'),
+    ('\u{03d3}', '\u{03d2}', '\u{0301}'), ('\u{03d4}', '\u{03d2}', '\u{0308}'), ('\u{0400}', '\u{0415}', '\u{0300}'),
+    ('\u{0401}', '\u{0415}', '\u{0308}'), ('\u{0403}', '\u{0413}', '\u{0301}'), ('\u{0407}', '\u{0406}', '\u{0308}'),
+    ('\u{040c}', '\u{041a}', '\u{0301}'), ('\u{040d}', '\u{0418}', '\u{0300}'), ('\u{040e}', '\u{0423}', '\u{0306}'),
+    ('\u{0419}', '\u{0418}', '\u{0306}'), ('\u{0439}', '\u{0438}', '\u{0306}'), ('\u{0450}', '\u{0435}', '\u{0300}'),
+    ('\u{0451}', '\u{0435}', '\u{0308}'), ('\u{0453}', '\u{0433}', '\u{0301}'), ('\u{0457}', '\u{0456}', '\u{0308}'),
+    ('\u{045c}', '\u{043a}', '\u{0301}'), ('\u{045d}', '\u{0438}', '\u{0300}'), ('\u{045e}', '\u{0443}', '\u{0306}'),
+    ('\u{0476}', '\u{0474}', '\u{030f}'), ('\u{0477}', '\u{0475}', '\u{030f}'), ('\u{04c1}', '\u{0416}', '\u{0306}'),
+    ('\u{04c2}', '\u{0436}', '\u{0306}'), ('\u{04d0}', '\u{0410}', '\u{0306}'), ('\u{04d1}', '\u{0430}', '\u{0306}'),
+    ('\u{04d2}', '\u{0410}', '\u{0308}'), ('\u{04d3}', '\u{0430}', '\u{0308}'), ('\u{04d6}', '\u{0415}', '\u{0306}'),
+    ('\u{04d7}', '\u{0435}', '\u{0306}'), ('\u{04da}', '\u{04d8}', '\u{0308}'), ('\u{04db}', '\u{04d9}', '\u{0308}'),
+    ('\u{04dc}', '\u{0416}', '\u{0308}'), ('\u{04dd}', '\u{0436}', '\u{0308}'), ('\u{04de}', '\u{0417}', '\u{0308}'),
+    ('\u{04df}', '\u{0437}', '\u{0308}'), ('\u{04e2}', '\u{0418}', '\u{0304}'), ('\u{04e3}', '\u{0438}', '\u{0304}'),
+    ('\u{04e4}', '\u{0418}', '\u{0308}'), ('\u{04e5}', '\u{0438}', '\u{0308}'), ('\u{04e6}', '\u{041e}', '\u{0308}'),
+    ('\u{04e7}', '\u{043e}', '\u{0308}'), ('\u{04ea}', '\u{04e8}', '\u{0308}'), ('\u{04eb}', '\u{04e9}', '\u{0308}'),
+    ('\u{04ec}', '\u{042d}', '\u{0308}'), ('\u{04ed}', '\u{044d}', '\u{0308}'), ('\u{04ee}', '\u{0423}', '\u{0304}'),
+    ('\u{04ef}', '\u{0443}', '\u{0304}'), ('\u{04f0}', '\u{0423}', '\u{0308}'), ('\u{04f1}', '\u{0443}', '\u{0308}'),
+    ('\u{04f2}', '\u{0423}', '\u{030b}'), ('\u{04f3}', '\u{0443}', '\u{030b}'), ('\u{04f4}', '\u{0427}', '\u{0308}'),
+    ('\u{04f5}', '\u{0447}', '\u{0308}'), ('\u{04f8}', '\u{042b}', '\u{0308}'), ('\u{04f9}', '\u{044b}', '\u{0308}'),
+    ('\u{1e00}', '\u{0041}', '\u{0325}'), ('\u{1e01}', '\u{0061}', '\u{0325}'), ('\u{1e02}', '\u{0042}', '\u{0307}'),
+    ('\u{1e03}', '\u{0062}', '\u{0307}'), ('\u{1e04}', '\u{0042}', '\u{0323}'), ('\u{1e05}', '\u{0062}', '\u{0323}'),
+    ('\u{1e06}', '\u{0042}', '\u{0331}'), ('\u{1e07}', '\u{0062}', '\u{0331}'), ('\u{1e08}', '\u{00c7}', '\u{0301}'),
+    ('\u{1e09}', '\u{00e7}', '\u{0301}'), ('\u{1e0a}', '\u{0044}', '\u{0307}'), ('\u{1e0b}', '\u{0064}', '\u{0307}'),
+    ('\u{1e0c}', '\u{0044}', '\u{0323}'), ('\u{1e0d}', '\u{0064}', '\u{0323}'), ('\u{1e0e}', '\u{0044}', '\u{0331}'),
+    ('\u{1e0f}', '\u{0064}', '\u{0331}'), ('\u{1e10}', '\u{0044}', '\u{0327}'), ('\u{1e11}', '\u{0064}', '\u{0327}'),
+    ('\u{1e12}', '\u{0044}', '\u{032d}'), ('\u{1e13}', '\u{0064}', '\u{032d}'), ('\u{1e14}', '\u{0112}', '\u{0300}'),
+    ('\u{1e15}', '\u{0113}', '\u{0300}'), ('\u{1e16}', '\u{0112}', '\u{0301}'), ('\u{1e17}', '\u{0113}', '\u{0301}'),
+    ('\u{1e18}', '\u{0045}', '\u{032d}'), ('\u{1e19}', '\u{0065}', '\u{032d}'), ('\u{1e1a}', '\u{0045}', '\u{0330}'),
+    ('\u{1e1b}', '\u{0065}', '\u{0330}'), ('\u{1e1c}', '\u{0228}', '\u{0306}'), ('\u{1e1d}', '\u{0229}', '\u{0306}'),
+    ('\u{1e1e}', '\u{0046}', '\u{0307}'), ('\u{1e1f}', '\u{0066}', '\u{0307}'), ('\u{1e20}', '\u{0047}', '\u{0304}'),
+    ('\u{1e21}', '\u{0067}', '\u{0304}'), ('\u{1e22}', '\u{0048}', '\u{0307}'), ('\u{1e23}', '\u{0068}', '\u{0307}'),
+    ('\u{1e24}', '\u{0048}', '\u{0323}'), ('\u{1e25}', '\u{0068}', '\u{0323}'), ('\u{1e26}', '\u{0048}', '\u{0308}'),
+    ('\u{1e27}', '\u{0068}', '\u{0308}'), ('\u{1e28}', '\u{0048}', '\u{0327}'), ('\u{1e29}', '\u{0068}', '\u{0327}'),
+    ('\u{1e2a}', '\u{0048}', '\u{032e}'), ('\u{1e2b}', '\u{0068}', '\u{032e}'), ('\u{1e2c}', '\u{0049}', '\u{0330}'),
+    ('\u{1e2d}', '\u{0069}', '\u{0330}'), ('\u{1e2e}', '\u{00cf}', '\u{0301}'), ('\u{1e2f}', '\u{00ef}', '\u{0301}'),
+    ('\u{1e30}', '\u{004b}', '\u{0301}'), ('\u{1e31}', '\u{006b}', '\u{0301}'), ('\u{1e32}', '\u{004b}', '\u{0323}'),
+    ('\u{1e33}', '\u{006b}', '\u{0323}'), ('\u{1e34}', '\u{004b}', '\u{0331}'), ('\u{1e35}', '\u{006b}', '\u{0331}'),
+    ('\u{1e36}', '\u{004c}', '\u{0323}'), ('\u{1e37}', '\u{006c}', '\u{0323}'), ('\u{1e38}', '\u{1e36}', '\u{0304}'),
+    ('\u{1e39}', '\u{1e37}', '\u{0304}'), ('\u{1e3a}', '\u{004c}', '\u{0331}'), ('\u{1e3b}', '\u{006c}', '\u{0331}'),
+    ('\u{1e3c}', '\u{004c}', '\u{032d}'), ('\u{1e3d}', '\u{006c}', '\u{032d}'), ('\u{1e3e}', '\u{004d}', '\u{0301}'),
+    ('\u{1e3f}', '\u{006d}', '\u{0301}'), ('\u{1e40}', '\u{004d}', '\u{0307}'), ('\u{1e41}', '\u{006d}', '\u{0307}'),
+    ('\u{1e42}', '\u{004d}', '\u{0323}'), ('\u{1e43}', '\u{006d}', '\u{0323}'), ('\u{1e44}', '\u{004e}', '\u{0307}'),
+    ('\u{1e45}', '\u{006e}', '\u{0307}'), ('\u{1e46}', '\u{004e}', '\u{0323}'), ('\u{1e47}', '\u{006e}', '\u{0323}'),
+    ('\u{1e48}', '\u{004e}', '\u{0331}'), ('\u{1e49}', '\u{006e}', '\u{0331}'), ('\u{1e4a}', '\u{004e}', '\u{032d}'),
+    ('\u{1e4b}', '\u{006e}', '\u{032d}'), ('\u{1e4c}', '\u{00d5}', '\u{0301}'), ('\u{1e4d}', '\u{00f5}', '\u{0301}'),
+    ('\u{1e4e}', '\u{00d5}', '\u{0308}'), ('\u{1e4f}', '\u{00f5}', '\u{0308}'), ('\u{1e50}', '\u{014c}', '\u{0300}'),
+    ('\u{1e51}', '\u{014d}', '\u{0300}'), ('\u{1e52}', '\u{014c}', '\u{0301}'), ('\u{1e53}', '\u{014d}', '\u{0301}'),
+    ('\u{1e54}', '\u{0050}', '\u{0301}'), ('\u{1e55}', '\u{0070}', '\u{0301}'), ('\u{1e56}', '\u{0050}', '\u{0307}'),
+    ('\u{1e57}', '\u{0070}', '\u{0307}'), ('\u{1e58}', '\u{0052}', '\u{0307}'), ('\u{1e59}', '\u{0072}', '\u{0307}'),
+    ('\u{1e5a}', '\u{0052}', '\u{0323}'), ('\u{1e5b}', '\u{0072}', '\u{0323}'), ('\u{1e5c}', '\u{1e5a}', '\u{0304}'),
+    ('\u{1e5d}', '\u{1e5b}', '\u{0304}'), ('\u{1e5e}', '\u{0052}', '\u{0331}'), ('\u{1e5f}', '\u{0072}', '\u{0331}'),
+    ('\u{1e60}', '\u{0053}', '\u{0307}'), ('\u{1e61}', '\u{0073}', '\u{0307}'), ('\u{1e62}', '\u{0053}', '\u{0323}'),
+    ('\u{1e63}', '\u{0073}', '\u{0323}'), ('\u{1e64}', '\u{015a}', '\u{0307}'), ('\u{1e65}', '\u{015b}', '\u{0307}'),
+    ('\u{1e66}', '\u{0160}', '\u{0307}'), ('\u{1e67}', '\u{0161}', '\u{0307}'), ('\u{1e68}', '\u{1e62}', '\u{0307}'),
+    ('\u{1e69}', '\u{1e63}', '\u{0307}'), ('\u{1e6a}', '\u{0054}', '\u{0307}'), ('\u{1e6b}', '\u{0074}', '\u{0307}'),
+    ('\u{1e6c}', '\u{0054}', '\u{0323}'), ('\u{1e6d}', '\u{0074}', '\u{0323}'), ('\u{1e6e}', '\u{0054}', '\u{0331}'),
+    ('\u{1e6f}', '\u{0074}', '\u{0331}'), ('\u{1e70}', '\u{0054}', '\u{032d}'), ('\u{1e71}', '\u{0074}', '\u{032d}'),
+    ('\u{1e72}', '\u{0055}', '\u{0324}'), ('\u{1e73}', '\u{0075}', '\u{0324}'), ('\u{1e74}', '\u{0055}', '\u{0330}'),
+    ('\u{1e75}', '\u{0075}', '\u{0330}'), ('\u{1e76}', '\u{0055}', '\u{032d}'), ('\u{1e77}', '\u{0075}', '\u{032d}'),
+    ('\u{1e78}', '\u{0168}', '\u{0301}'), ('\u{1e79}', '\u{0169}', '\u{0301}'), ('\u{1e7a}', '\u{016a}', '\u{0308}'),
+    ('\u{1e7b}', '\u{016b}', '\u{0308}'), ('\u{1e7c}', '\u{0056}', '\u{0303}'), ('\u{1e7d}', '\u{0076}', '\u{0303}'),
+    ('\u{1e7e}', '\u{0056}', '\u{0323}'), ('\u{1e7f}', '\u{0076}', '\u{0323}'), ('\u{1e80}', '\u{0057}', '\u{0300}'),
+    ('\u{1e81}', '\u{0077}', '\u{0300}'), ('\u{1e82}', '\u{0057}', '\u{0301}'), ('\u{1e83}', '\u{0077}', '\u{0301}'),
+    ('\u{1e84}', '\u{0057}', '\u{0308}'), ('\u{1e85}', '\u{0077}', '\u{0308}'), ('\u{1e86}', '\u{0057}', '\u{0307}'),
+    ('\u{1e87}', '\u{0077}', '\u{0307}'), ('\u{1e88}', '\u{0057}', '\u{0323}'), ('\u{1e89}', '\u{0077}', '\u{0323}'),
+    ('\u{1e8a}', '\u{0058}', '\u{0307}'), ('\u{1e8b}', '\u{0078}', '\u{0307}'), ('\u{1e8c}', '\u{0058}', '\u{0308}'),
+    ('\u{1e8d}', '\u{0078}', '\u{0308}'), ('\u{1e8e}', '\u{0059}', '\u{0307}'), ('\u{1e8f}', '\u{0079}', '\u{0307}'),
+    ('\u{1e90}', '\u{005a}', '\u{0302}'), ('\u{1e91}', '\u{007a}', '\u{0302}'), ('\u{1e92}', '\u{005a}', '\u{0323}'),
+    ('\u{1e93}', '\u{007a}', '\u{0323}'), ('\u{1e94}', '\u{005a}', '\u{0331}'), ('\u{1e95}', '\u{007a}', '\u{0331}'),
+    ('\u{1e96}', '\u{0068}', '\u{0331}'), ('\u{1e97}', '\u{0074}', '\u{0308}'), ('\u{1e98}', '\u{0077}', '\u{030a}'),
+    ('\u{1e99}', '\u{0079}', '\u{030a}'), ('\u{1e9b}', '\u{017f}', '\u{0307}'), ('\u{1ea0}', '\u{0041}', '\u{0323}'),
+    ('\u{1ea1}', '\u{0061}', '\u{0323}'), ('\u{1ea2}', '\u{0041}', '\u{0309}'), ('\u{1ea3}', '\u{0061}', '\u{0309}'),
+    ('\u{1ea4}', '\u{00c2}', '\u{0301}'), ('\u{1ea5}', '\u{00e2}', '\u{0301}'), ('\u{1ea6}', '\u{00c2}', '\u{0300}'),
+    ('\u{1ea7}', '\u{00e2}', '\u{0300}'), ('\u{1ea8}', '\u{00c2}', '\u{0309}'), ('\u{1ea9}', '\u{00e2}', '\u{0309}'),
+    ('\u{1eaa}', '\u{00c2}', '\u{0303}'), ('\u{1eab}', '\u{00e2}', '\u{0303}'), ('\u{1eac}', '\u{1ea0}', '\u{0302}'),
+    ('\u{1ead}', '\u{1ea1}', '\u{0302}'), ('\u{1eae}', '\u{0102}', '\u{0301}'), ('\u{1eaf}', '\u{0103}', '\u{0301}'),
+    ('\u{1eb0}', '\u{0102}', '\u{0300}'), ('\u{1eb1}', '\u{0103}', '\u{0300}'), ('\u{1eb2}', '\u{0102}', '\u{0309}'),
+    ('\u{1eb3}', '\u{0103}', '\u{0309}'), ('\u{1eb4}', '\u{0102}', '\u{0303}'), ('\u{1eb5}', '\u{0103}', '\u{0303}'),
+    ('\u{1eb6}', '\u{1ea0}', '\u{0306}'), ('\u{1eb7}', '\u{1ea1}', '\u{0306}'), ('\u{1eb8}', '\u{0045}', '\u{0323}'),
+    ('\u{1eb9}', '\u{0065}', '\u{0323}'), ('\u{1eba}', '\u{0045}', '\u{0309}'), ('\u{1ebb}', '\u{0065}', '\u{0309}'),
+    ('\u{1ebc}', '\u{0045}', '\u{0303}'), ('\u{1ebd}', '\u{0065}', '\u{0303}'), ('\u{1ebe}', '\u{00ca}', '\u{0301}'),
+    ('\u{1ebf}', '\u{00ea}', '\u{0301}'), ('\u{1ec0}', '\u{00ca}', '\u{0300}'), ('\u{1ec1}', '\u{00ea}', '\u{0300}'),
+    ('\u{1ec2}', '\u{00ca}', '\u{0309}'), ('\u{1ec3}', '\u{00ea}', '\u{0309}'), ('\u{1ec4}', '\u{00ca}', '\u{0303}'),
+    ('\u{1ec5}', '\u{00ea}', '\u{0303}'), ('\u{1ec6}', '\u{1eb8}', '\u{0302}'), ('\u{1ec7}', '\u{1eb9}', '\u{0302}'),
+    ('\u{1ec8}', '\u{0049}', '\u{0309}'), ('\u{1ec9}', '\u{0069}', '\u{0309}'), ('\u{1eca}', '\u{0049}', '\u{0323}'),
+    ('\u{1ecb}', '\u{0069}', '\u{0323}'), ('\u{1ecc}', '\u{004f}', '\u{0323}'), ('\u{1ecd}', '\u{006f}', '\u{0323}'),
+    ('\u{1ece}', '\u{004f}', '\u{0309}'), ('\u{1ecf}', '\u{006f}', '\u{0309}'), ('\u{1ed0}', '\u{00d4}', '\u{0301}'),
+    ('\u{1ed1}', '\u{00f4}', '\u{0301}'), ('\u{1ed2}', '\u{00d4}', '\u{0300}'), ('\u{1ed3}', '\u{00f4}', '\u{0300}'),
+    ('\u{1ed4}', '\u{00d4}', '\u{0309}'), ('\u{1ed5}', '\u{00f4}', '\u{0309}'), ('\u{1ed6}', '\u{00d4}', '\u{0303}'),
+    ('\u{1ed7}', '\u{00f4}', '\u{0303}'), ('\u{1ed8}', '\u{1ecc}', '\u{0302}'), ('\u{1ed9}', '\u{1ecd}', '\u{0302}'),
+    ('\u{1eda}', '\u{01a0}', '\u{0301}'), ('\u{1edb}', '\u{01a1}', '\u{0301}'), ('\u{1edc}', '\u{01a0}', '\u{0300}'),
+    ('\u{1edd}', '\u{01a1}', '\u{0300}'), ('\u{1ede}', '\u{01a0}', '\u{0309}'), ('\u{1edf}', '\u{01a1}', '\u{0309}'),
+    ('\u{1ee0}', '\u{01a0}', '\u{0303}'), ('\u{1ee1}', '\u{01a1}', '\u{0303}'), ('\u{1ee2}', '\u{01a0}', '\u{0323}'),
+    ('\u{1ee3}', '\u{01a1}', '\u{0323}'), ('\u{1ee4}', '\u{0055}', '\u{0323}'), ('\u{1ee5}', '\u{0075}', '\u{0323}'),
+    ('\u{1ee6}', '\u{0055}', '\u{0309}'), ('\u{1ee7}', '\u{0075}', '\u{0309}'), ('\u{1ee8}', '\u{01af}', '\u{0301}'),
+    ('\u{1ee9}', '\u{01b0}', '\u{0301}'), ('\u{1eea}', '\u{01af}', '\u{0300}'), ('\u{1eeb}', '\u{01b0}', '\u{0300}'),
+    ('\u{1eec}', '\u{01af}', '\u{0309}'), ('\u{1eed}', '\u{01b0}', '\u{0309}'), ('\u{1eee}', '\u{01af}', '\u{0303}'),
+    ('\u{1eef}', '\u{01b0}', '\u{0303}'), ('\u{1ef0}', '\u{01af}', '\u{0323}'), ('\u{1ef1}', '\u{01b0}', '\u{0323}'),
+    ('\u{1ef2}', '\u{0059}', '\u{0300}'), ('\u{1ef3}', '\u{0079}', '\u{0300}'), ('\u{1ef4}', '\u{0059}', '\u{0323}'),
+    ('\u{1ef5}', '\u{0079}', '\u{0323}'), ('\u{1ef6}', '\u{0059}', '\u{0309}'), ('\u{1ef7}', '\u{0079}', '\u{0309}'),
+    ('\u{1ef8}', '\u{0059}', '\u{0303}'), ('\u{1ef9}', '\u{0079}', '\u{0303}'), ('\u{1f00}', '\u{03b1}', '\u{0313}'),
+    ('\u{1f01}', '\u{03b1}', '\u{0314}'), ('\u{1f02}', '\u{1f00}', '\u{0300}'), ('\u{1f03}', '\u{1f01}', '\u{0300}'),
+    ('\u{1f04}', '\u{1f00}', '\u{0301}'), ('\u{1f05}', '\u{1f01}', '\u{0301}'), ('\u{1f06}', '\u{1f00}', '\u{0342}'),
+    ('\u{1f07}', '\u{1f01}', '\u{0342}'), ('\u{1f08}', '\u{0391}', '\u{0313}'), ('\u{1f09}', '\u{0391}', '\u{0314}'),
+    ('\u{1f0a}', '\u{1f08}', '\u{0300}'), ('\u{1f0b}', '\u{1f09}', '\u{0300}'), ('\u{1f0c}', '\u{1f08}', '\u{0301}'),
+    ('\u{1f0d}', '\u{1f09}', '\u{0301}'), ('\u{1f0e}', '\u{1f08}', '\u{0342}'), ('\u{1f0f}', '\u{1f09}', '\u{0342}'),
+    ('\u{1f10}', '\u{03b5}', '\u{0313}'), ('\u{1f11}', '\u{03b5}', '\u{0314}'), ('\u{1f12}', '\u{1f10}', '\u{0300}'),
+    ('\u{1f13}', '\u{1f11}', '\u{0300}'), ('\u{1f14}', '\u{1f10}', '\u{0301}'), ('\u{1f15}', '\u{1f11}', '\u{0301}'),
+    ('\u{1f18}', '\u{0395}', '\u{0313}'), ('\u{1f19}', '\u{0395}', '\u{0314}'), ('\u{1f1a}', '\u{1f18}', '\u{0300}'),
+    ('\u{1f1b}', '\u{1f19}', '\u{0300}'), ('\u{1f1c}', '\u{1f18}', '\u{0301}'), ('\u{1f1d}', '\u{1f19}', '\u{0301}'),
+    ('\u{1f20}', '\u{03b7}', '\u{0313}'), ('\u{1f21}', '\u{03b7}', '\u{0314}'), ('\u{1f22}', '\u{1f20}', '\u{0300}'),
+    ('\u{1f23}', '\u{1f21}', '\u{0300}'), ('\u{1f24}', '\u{1f20}', '\u{0301}'), ('\u{1f25}', '\u{1f21}', '\u{0301}'),
+    ('\u{1f26}', '\u{1f20}', '\u{0342}'), ('\u{1f27}', '\u{1f21}', '\u{0342}'), ('\u{1f28}', '\u{0397}', '\u{0313}'),
+    ('\u{1f29}', '\u{0397}', '\u{0314}'), ('\u{1f2a}', '\u{1f28}', '\u{0300}'), ('\u{1f2b}', '\u{1f29}', '\u{0300}'),
+    ('\u{1f2c}', '\u{1f28}', '\u{0301}'), ('\u{1f2d}', '\u{1f29}', '\u{0301}'), ('\u{1f2e}', '\u{1f28}', '\u{0342}'),
+    ('\u{1f2f}', '\u{1f29}', '\u{0342}'), ('\u{1f30}', '\u{03b9}', '\u{0313}'), ('\u{1f31}', '\u{03b9}', '\u{0314}'),
+    ('\u{1f32}', '\u{1f30}', '\u{0300}'), ('\u{1f33}', '\u{1f31}', '\u{0300}'), ('\u{1f34}', '\u{1f30}', '\u{0301}'),
+    ('\u{1f35}', '\u{1f31}', '\u{0301}'), ('\u{1f36}', '\u{1f30}', '\u{0342}'), ('\u{1f37}', '\u{1f31}', '\u{0342}'),
+    ('\u{1f38}', '\u{0399}', '\u{0313}'), ('\u{1f39}', '\u{0399}', '\u{0314}'), ('\u{1f3a}', '\u{1f38}', '\u{0300}'),
+    ('\u{1f3b}', '\u{1f39}', '\u{0300}'), ('\u{1f3c}', '\u{1f38}', '\u{0301}'), ('\u{1f3d}', '\u{1f39}', '\u{0301}'),
+    ('\u{1f3e}', '\u{1f38}', '\u{0342}'), ('\u{1f3f}', '\u{1f39}', '\u{0342}'), ('\u{1f40}', '\u{03bf}', '\u{0313}'),
+    ('\u{1f41}', '\u{03bf}', '\u{0314}'), ('\u{1f42}', '\u{1f40}', '\u{0300}'), ('\u{1f43}', '\u{1f41}', '\u{0300}'),
+    ('\u{1f44}', '\u{1f40}', '\u{0301}'), ('\u{1f45}', '\u{1f41}', '\u{0301}'), ('\u{1f48}', '\u{039f}', '\u{0313}'),
+    ('\u{1f49}', '\u{039f}', '\u{0314}'), ('\u{1f4a}', '\u{1f48}', '\u{0300}'), ('\u{1f4b}', '\u{1f49}', '\u{0300}'),
+    ('\u{1f4c}', '\u{1f48}', '\u{0301}'), ('\u{1f4d}', '\u{1f49}', '\u{0301}'), ('\u{1f50}', '\u{03c5}', '\u{0313}'),
+    ('\u{1f51}', '\u{03c5}', '\u{0314}'), ('\u{1f52}', '\u{1f50}', '\u{0300}'), ('\u{1f53}', '\u{1f51}', '\u{0300}'),
+    ('\u{1f54}', '\u{1f50}', '\u{0301}'), ('\u{1f55}', '\u{1f51}', '\u{0301}'), ('\u{1f56}', '\u{1f50}', '\u{0342}'),
+    ('\u{1f57}', '\u{1f51}', '\u{0342}'), ('\u{1f59}', '\u{03a5}', '\u{0314}'), ('\u{1f5b}', '\u{1f59}', '\u{0300}'),
+    ('\u{1f5d}', '\u{1f59}', '\u{0301}'), ('\u{1f5f}', '\u{1f59}', '\u{0342}'), ('\u{1f60}', '\u{03c9}', '\u{0313}'),
+    ('\u{1f61}', '\u{03c9}', '\u{0314}'), ('\u{1f62}', '\u{1f60}', '\u{0300}'), ('\u{1f63}', '\u{1f61}', '\u{0300}'),
+    ('\u{1f64}', '\u{1f60}', '\u{0301}'), ('\u{1f65}', '\u{1f61}', '\u{0301}'), ('\u{1f66}', '\u{1f60}', '\u{0342}'),
+    ('\u{1f67}', '\u{1f61}', '\u{0342}'), ('\u{1f68}', '\u{03a9}', '\u{0313}'), ('\u{1f69}', '\u{03a9}', '\u{0314}'),
+    ('\u{1f6a}', '\u{1f68}', '\u{0300}'), ('\u{1f6b}', '\u{1f69}', '\u{0300}'), ('\u{1f6c}', '\u{1f68}', '\u{0301}'),
+    ('\u{1f6d}', '\u{1f69}', '\u{0301}'), ('\u{1f6e}', '\u{1f68}', '\u{0342}'), ('\u{1f6f}', '\u{1f69}', '\u{0342}'),
+    ('\u{1f70}', '\u{03b1}', '\u{0300}'), ('\u{1f72}', '\u{03b5}', '\u{0300}'), ('\u{1f74}', '\u{03b7}', '\u{0300}'),
+    ('\u{1f76}', '\u{03b9}', '\u{0300}'), ('\u{1f78}', '\u{03bf}', '\u{0300}'), ('\u{1f7a}', '\u{03c5}', '\u{0300}'),
+    ('\u{1f7c}', '\u{03c9}', '\u{0300}'), ('\u{1f80}', '\u{1f00}', '\u{0345}'), ('\u{1f81}', '\u{1f01}', '\u{0345}'),
+    ('\u{1f82}', '\u{1f02}', '\u{0345}'), ('\u{1f83}', '\u{1f03}', '\u{0345}'), ('\u{1f84}', '\u{1f04}', '\u{0345}'),
+    ('\u{1f85}', '\u{1f05}', '\u{0345}'), ('\u{1f86}', '\u{1f06}', '\u{0345}'), ('\u{1f87}', '\u{1f07}', '\u{0345}'),
+    ('\u{1f88}', '\u{1f08}', '\u{0345}'), ('\u{1f89}', '\u{1f09}', '\u{0345}'), ('\u{1f8a}', '\u{1f0a}', '\u{0345}'),
+    ('\u{1f8b}', '\u{1f0b}', '\u{0345}'), ('\u{1f8c}', '\u{1f0c}', '\u{0345}'), ('\u{1f8d}', '\u{1f0d}', '\u{0345}'),
+    ('\u{1f8e}', '\u{1f0e}', '\u{0345}'), ('\u{1f8f}', '\u{1f0f}', '\u{0345}'), ('\u{1f90}', '\u{1f20}', '\u{0345}'),
+    ('\u{1f91}', '\u{1f21}', '\u{0345}'), ('\u{1f92}', '\u{1f22}', '\u{0345}'), ('\u{1f93}', '\u{1f23}', '\u{0345}'),
+    ('\u{1f94}', '\u{1f24}', '\u{0345}'), ('\u{1f95}', '\u{1f25}', '\u{0345}'), ('\u{1f96}', '\u{1f26}', '\u{0345}'),
+    ('\u{1f97}', '\u{1f27}', '\u{0345}'), ('\u{1f98}', '\u{1f28}', '\u{0345}'), ('\u{1f99}', '\u{1f29}', '\u{0345}'),
+    ('\u{1f9a}', '\u{1f2a}', '\u{0345}'), ('\u{1f9b}', '\u{1f2b}', '\u{0345}'), ('\u{1f9c}', '\u{1f2c}', '\u{0345}'),
+    ('\u{1f9d}', '\u{1f2d}', '\u{0345}'), ('\u{1f9e}', '\u{1f2e}', '\u{0345}'), ('\u{1f9f}', '\u{1f2f}', '\u{0345}'),
+    ('\u{1fa0}', '\u{1f60}', '\u{0345}'), ('\u{1fa1}', '\u{1f61}', '\u{0345}'), ('\u{1fa2}', '\u{1f62}', '\u{0345}'),
+    ('\u{1fa3}', '\u{1f63}', '\u{0345}'), ('\u{1fa4}', '\u{1f64}', '\u{0345}'), ('\u{1fa5}', '\u{1f65}', '\u{0345}'),
+    ('\u{1fa6}', '\u{1f66}', '\u{0345}'), ('\u{1fa7}', '\u{1f67}', '\u{0345}'), ('\u{1fa8}', '\u{1f68}', '\u{0345}'),
+    ('\u{1fa9}', '\u{1f69}', '\u{0345}'), ('\u{1faa}', '\u{1f6a}', '\u{0345}'), ('\u{1fab}', '\u{1f6b}', '\u{0345}'),
+    ('\u{1fac}', '\u{1f6c}', '\u{0345}'), ('\u{1fad}', '\u{1f6d}', '\u{0345}'), ('\u{1fae}', '\u{1f6e}', '\u{0345}'),
+    ('\u{1faf}', '\u{1f6f}', '\u{0345}'), ('\u{1fb0}', '\u{03b1}', '\u{0306}'), ('\u{1fb1}', '\u{03b1}', '\u{0304}'),
+    ('\u{1fb2}', '\u{1f70}', '\u{0345}'), ('\u{1fb3}', '\u{03b1}', '\u{0345}'), ('\u{1fb4}', '\u{03ac}', '\u{0345}'),
+    ('\u{1fb6}', '\u{03b1}', '\u{0342}'), ('\u{1fb7}', '\u{1fb6}', '\u{0345}'), ('\u{1fb8}', '\u{0391}', '\u{0306}'),
+    ('\u{1fb9}', '\u{0391}', '\u{0304}'), ('\u{1fba}', '\u{0391}', '\u{0300}'), ('\u{1fbc}', '\u{0391}', '\u{0345}'),
+    ('\u{1fc1}', '\u{00a8}', '\u{0342}'), ('\u{1fc2}', '\u{1f74}', '\u{0345}'), ('\u{1fc3}', '\u{03b7}', '\u{0345}'),
+    ('\u{1fc4}', '\u{03ae}', '\u{0345}'), ('\u{1fc6}', '\u{03b7}', '\u{0342}'), ('\u{1fc7}', '\u{1fc6}', '\u{0345}'),
+    ('\u{1fc8}', '\u{0395}', '\u{0300}'), ('\u{1fca}', '\u{0397}', '\u{0300}'), ('\u{1fcc}', '\u{0397}', '\u{0345}'),
+    ('\u{1fcd}', '\u{1fbf}', '\u{0300}'), ('\u{1fce}', '\u{1fbf}', '\u{0301}'), ('\u{1fcf}', '\u{1fbf}', '\u{0342}'),
+    ('\u{1fd0}', '\u{03b9}', '\u{0306}'), ('\u{1fd1}', '\u{03b9}', '\u{0304}'), ('\u{1fd2}', '\u{03ca}', '\u{0300}'),
+    ('\u{1fd6}', '\u{03b9}', '\u{0342}'), ('\u{1fd7}', '\u{03ca}', '\u{0342}'), ('\u{1fd8}', '\u{0399}', '\u{0306}'),
+    ('\u{1fd9}', '\u{0399}', '\u{0304}'), ('\u{1fda}', '\u{0399}', '\u{0300}'), ('\u{1fdd}', '\u{1ffe}', '\u{0300}'),
+    ('\u{1fde}', '\u{1ffe}', '\u{0301}'), ('\u{1fdf}', '\u{1ffe}', '\u{0342}'), ('\u{1fe0}', '\u{03c5}', '\u{0306}'),
+    ('\u{1fe1}', '\u{03c5}', '\u{0304}'), ('\u{1fe2}', '\u{03cb}', '\u{0300}'), ('\u{1fe4}', '\u{03c1}', '\u{0313}'),
+    ('\u{1fe5}', '\u{03c1}', '\u{0314}'), ('\u{1fe6}', '\u{03c5}', '\u{0342}'), ('\u{1fe7}', '\u{03cb}', '\u{0342}'),
+    ('\u{1fe8}', '\u{03a5}', '\u{0306}'), ('\u{1fe9}', '\u{03a5}', '\u{0304}'), ('\u{1fea}', '\u{03a5}', '\u{0300}'),
+    ('\u{1fec}', '\u{03a1}', '\u{0314}'), ('\u{1fed}', '\u{00a8}', '\u{0300}'), ('\u{1ff2}', '\u{1f7c}', '\u{0345}'),
+    ('\u{1ff3}', '\u{03c9}', '\u{0345}'), ('\u{1ff4}', '\u{03ce}', '\u{0345}'), ('\u{1ff6}', '\u{03c9}', '\u{0342}'),
+    ('\u{1ff7}', '\u{1ff6}', '\u{0345}'), ('\u{1ff8}', '\u{039f}', '\u{0300}'), ('\u{1ffa}', '\u{03a9}', '\u{0300}'),
+    ('\u{1ffc}', '\u{03a9}', '\u{0345}'),
+];
+
+// The same pairs, sorted by base and mark for composition.
+pub(crate) const COMPOSITIONS: &[(char, char, char)] = &[
+    ('\u{0041}', '\u{0300}', '\u{00c0}'), ('\u{0041}', '\u{0301}', '\u{00c1}'), ('\u{0041}', '\u{0302}', '\u{00c2}'),
+    ('\u{0041}', '\u{0303}', '\u{00c3}'), ('\u{0041}', '\u{0304}', '\u{0100}'), ('\u{0041}', '\u{0306}', '\u{0102}'),
+    ('\u{0041}', '\u{0307}', '\u{0226}'), ('\u{0041}', '\u{0308}', '\u{00c4}'), ('\u{0041}', '\u{0309}', '\u{1ea2}'),
+    ('\u{0041}', '\u{030a}', '\u{00c5}'), ('\u{0041}', '\u{030c}', '\u{01cd}'), ('\u{0041}', '\u{030f}', '\u{0200}'),
+    ('\u{0041}', '\u{0311}', '\u{0202}'), ('\u{0041}', '\u{0323}', '\u{1ea0}'), ('\u{0041}', '\u{0325}', '\u{1e00}'),
+    ('\u{0041}', '\u{0328}', '\u{0104}'), ('\u{0042}', '\u{0307}', '\u{1e02}'), ('\u{0042}', '\u{0323}', '\u{1e04}'),
+    ('\u{0042}', '\u{0331}', '\u{1e06}'), ('\u{0043}', '\u{0301}', '\u{0106}'), ('\u{0043}', '\u{0302}', '\u{0108}'),
+    ('\u{0043}', '\u{0307}', '\u{010a}'), ('\u{0043}', '\u{030c}', '\u{010c}'), ('\u{0043}', '\u{0327}', '\u{00c7}'),
+    ('\u{0044}', '\u{0307}', '\u{1e0a}'), ('\u{0044}', '\u{030c}', '\u{010e}'), ('\u{0044}', '\u{0323}', '\u{1e0c}'),
+    ('\u{0044}', '\u{0327}', '\u{1e10}'), ('\u{0044}', '\u{032d}', '\u{1e12}'), ('\u{0044}', '\u{0331}', '\u{1e0e}'),
+    ('\u{0045}', '\u{0300}', '\u{00c8}'), ('\u{0045}', '\u{0301}', '\u{00c9}'), ('\u{0045}', '\u{0302}', '\u{00ca}'),
+    ('\u{0045}', '\u{0303}', '\u{1ebc}'), ('\u{0045}', '\u{0304}', '\u{0112}'), ('\u{0045}', '\u{0306}', '\u{0114}'),
+    ('\u{0045}', '\u{0307}', '\u{0116}'), ('\u{0045}', '\u{0308}', '\u{00cb}'), ('\u{0045}', '\u{0309}', '\u{1eba}'),
+    ('\u{0045}', '\u{030c}', '\u{011a}'), ('\u{0045}', '\u{030f}', '\u{0204}'), ('\u{0045}', '\u{0311}', '\u{0206}'),
+    ('\u{0045}', '\u{0323}', '\u{1eb8}'), ('\u{0045}', '\u{0327}', '\u{0228}'), ('\u{0045}', '\u{0328}', '\u{0118}'),
+    ('\u{0045}', '\u{032d}', '\u{1e18}'), ('\u{0045}', '\u{0330}', '\u{1e1a}'), ('\u{0046}', '\u{0307}', '\u{1e1e}'),
+    ('\u{0047}', '\u{0301}', '\u{01f4}'), ('\u{0047}', '\u{0302}', '\u{011c}'), ('\u{0047}', '\u{0304}', '\u{1e20}'),
+    ('\u{0047}', '\u{0306}', '\u{011e}'), ('\u{0047}', '\u{0307}', '\u{0120}'), ('\u{0047}', '\u{030c}', '\u{01e6}'),
+    ('\u{0047}', '\u{0327}', '\u{0122}'), ('\u{0048}', '\u{0302}', '\u{0124}'), ('\u{0048}', '\u{0307}', '\u{1e22}'),
+    ('\u{0048}', '\u{0308}', '\u{1e26}'), ('\u{0048}', '\u{030c}', '\u{021e}'), ('\u{0048}', '\u{0323}', '\u{1e24}'),
+    ('\u{0048}', '\u{0327}', '\u{1e28}'), ('\u{0048}', '\u{032e}', '\u{1e2a}'), ('\u{0049}', '\u{0300}', '\u{00cc}'),
+    ('\u{0049}', '\u{0301}', '\u{00cd}'), ('\u{0049}', '\u{0302}', '\u{00ce}'), ('\u{0049}', '\u{0303}', '\u{0128}'),
+    ('\u{0049}', '\u{0304}', '\u{012a}'), ('\u{0049}', '\u{0306}', '\u{012c}'), ('\u{0049}', '\u{0307}', '\u{0130}'),
+    ('\u{0049}', '\u{0308}', '\u{00cf}'), ('\u{0049}', '\u{0309}', '\u{1ec8}'), ('\u{0049}', '\u{030c}', '\u{01cf}'),
+    ('\u{0049}', '\u{030f}', '\u{0208}'), ('\u{0049}', '\u{0311}', '\u{020a}'), ('\u{0049}', '\u{0323}', '\u{1eca}'),
+    ('\u{0049}', '\u{0328}', '\u{012e}'), ('\u{0049}', '\u{0330}', '\u{1e2c}'), ('\u{004a}', '\u{0302}', '\u{0134}'),
+    ('\u{004b}', '\u{0301}', '\u{1e30}'), ('\u{004b}', '\u{030c}', '\u{01e8}'), ('\u{004b}', '\u{0323}', '\u{1e32}'),
+    ('\u{004b}', '\u{0327}', '\u{0136}'), ('\u{004b}', '\u{0331}', '\u{1e34}'), ('\u{004c}', '\u{0301}', '\u{0139}'),
+    ('\u{004c}', '\u{030c}', '\u{013d}'), ('\u{004c}', '\u{0323}', '\u{1e36}'), ('\u{004c}', '\u{0327}', '\u{013b}'),
+    ('\u{004c}', '\u{032d}', '\u{1e3c}'), ('\u{004c}', '\u{0331}', '\u{1e3a}'), ('\u{004d}', '\u{0301}', '\u{1e3e}'),
+    ('\u{004d}', '\u{0307}', '\u{1e40}'), ('\u{004d}', '\u{0323}', '\u{1e42}'), ('\u{004e}', '\u{0300}', '\u{01f8}'),
+    ('\u{004e}', '\u{0301}', '\u{0143}'), ('\u{004e}', '\u{0303}', '\u{00d1}'), ('\u{004e}', '\u{0307}', '\u{1e44}'),
+    ('\u{004e}', '\u{030c}', '\u{0147}'), ('\u{004e}', '\u{0323}', '\u{1e46}'), ('\u{004e}', '\u{0327}', '\u{0145}'),
+    ('\u{004e}', '\u{032d}', '\u{1e4a}'), ('\u{004e}', '\u{0331}', '\u{1e48}'), ('\u{004f}', '\u{0300}', '\u{00d2}'),
+    ('\u{004f}', '\u{0301}', '\u{00d3}'), ('\u{004f}', '\u{0302}', '\u{00d4}'), ('\u{004f}', '\u{0303}', '\u{00d5}'),
+    ('\u{004f}', '\u{0304}', '\u{014c}'), ('\u{004f}', '\u{0306}', '\u{014e}'), ('\u{004f}', '\u{0307}', '\u{022e}'),
+    ('\u{004f}', '\u{0308}', '\u{00d6}'), ('\u{004f}', '\u{0309}', '\u{1ece}'), ('\u{004f}', '\u{030b}', '\u{0150}'),
+    ('\u{004f}', '\u{030c}', '\u{01d1}'), ('\u{004f}', '\u{030f}', '\u{020c}'), ('\u{004f}', '\u{0311}', '\u{020e}'),
+    ('\u{004f}', '\u{031b}', '\u{01a0}'), ('\u{004f}', '\u{0323}', '\u{1ecc}'), ('\u{004f}', '\u{0328}', '\u{01ea}'),
+    ('\u{0050}', '\u{0301}', '\u{1e54}'), ('\u{0050}', '\u{0307}', '\u{1e56}'), ('\u{0052}', '\u{0301}', '\u{0154}'),
+    ('\u{0052}', '\u{0307}', '\u{1e58}'), ('\u{0052}', '\u{030c}', '\u{0158}'), ('\u{0052}', '\u{030f}', '\u{0210}'),
+    ('\u{0052}', '\u{0311}', '\u{0212}'), ('\u{0052}', '\u{0323}', '\u{1e5a}'), ('\u{0052}', '\u{0327}', '\u{0156}'),
+    ('\u{0052}', '\u{0331}', '\u{1e5e}'), ('\u{0053}', '\u{0301}', '\u{015a}'), ('\u{0053}', '\u{0302}', '\u{015c}'),
+    ('\u{0053}', '\u{0307}', '\u{1e60}'), ('\u{0053}', '\u{030c}', '\u{0160}'), ('\u{0053}', '\u{0323}', '\u{1e62}'),
+    ('\u{0053}', '\u{0326}', '\u{0218}'), ('\u{0053}', '\u{0327}', '\u{015e}'), ('\u{0054}', '\u{0307}', '\u{1e6a}'),
+    ('\u{0054}', '\u{030c}', '\u{0164}'), ('\u{0054}', '\u{0323}', '\u{1e6c}'), ('\u{0054}', '\u{0326}', '\u{021a}'),
+    ('\u{0054}', '\u{0327}', '\u{0162}'), ('\u{0054}', '\u{032d}', '\u{1e70}'), ('\u{0054}', '\u{0331}', '\u{1e6e}'),
+    ('\u{0055}', '\u{0300}', '\u{00d9}'), ('\u{0055}', '\u{0301}', '\u{00da}'), ('\u{0055}', '\u{0302}', '\u{00db}'),
+    ('\u{0055}', '\u{0303}', '\u{0168}'), ('\u{0055}', '\u{0304}', '\u{016a}'), ('\u{0055}', '\u{0306}', '\u{016c}'),
+    ('\u{0055}', '\u{0308}', '\u{00dc}'), ('\u{0055}', '\u{0309}', '\u{1ee6}'), ('\u{0055}', '\u{030a}', '\u{016e}'),
+    ('\u{0055}', '\u{030b}', '\u{0170}'), ('\u{0055}', '\u{030c}', '\u{01d3}'), ('\u{0055}', '\u{030f}', '\u{0214}'),
+    ('\u{0055}', '\u{0311}', '\u{0216}'), ('\u{0055}', '\u{031b}', '\u{01af}'), ('\u{0055}', '\u{0323}', '\u{1ee4}'),
+    ('\u{0055}', '\u{0324}', '\u{1e72}'), ('\u{0055}', '\u{0328}', '\u{0172}'), ('\u{0055}', '\u{032d}', '\u{1e76}'),
+    ('\u{0055}', '\u{0330}', '\u{1e74}'), ('\u{0056}', '\u{0303}', '\u{1e7c}'), ('\u{0056}', '\u{0323}', '\u{1e7e}'),
+    ('\u{0057}', '\u{0300}', '\u{1e80}'), ('\u{0057}', '\u{0301}', '\u{1e82}'), ('\u{0057}', '\u{0302}', '\u{0174}'),
+    ('\u{0057}', '\u{0307}', '\u{1e86}'), ('\u{0057}', '\u{0308}', '\u{1e84}'), ('\u{0057}', '\u{0323}', '\u{1e88}'),
+    ('\u{0058}', '\u{0307}', '\u{1e8a}'), ('\u{0058}', '\u{0308}', '\u{1e8c}'), ('\u{0059}', '\u{0300}', '\u{1ef2}'),
+    ('\u{0059}', '\u{0301}', '\u{00dd}'), ('\u{0059}', '\u{0302}', '\u{0176}'), ('\u{0059}', '\u{0303}', '\u{1ef8}'),
+    ('\u{0059}', '\u{0304}', '\u{0232}'), ('\u{0059}', '\u{0307}', '\u{1e8e}'), ('\u{0059}', '\u{0308}', '\u{0178}'),
+    ('\u{0059}', '\u{0309}', '\u{1ef6}'), ('\u{0059}', '\u{0323}', '\u{1ef4}'), ('\u{005a}', '\u{0301}', '\u{0179}'),
+    ('\u{005a}', '\u{0302}', '\u{1e90}'), ('\u{005a}', '\u{0307}', '\u{017b}'), ('\u{005a}', '\u{030c}', '\u{017d}'),
+    ('\u{005a}', '\u{0323}', '\u{1e92}'), ('\u{005a}', '\u{0331}', '\u{1e94}'), ('\u{0061}', '\u{0300}', '\u{00e0}'),
+    ('\u{0061}', '\u{0301}', '\u{00e1}'), ('\u{0061}', '\u{0302}', '\u{00e2}'), ('\u{0061}', '\u{0303}', '\u{00e3}'),
+    ('\u{0061}', '\u{0304}', '\u{0101}'), ('\u{0061}', '\u{0306}', '\u{0103}'), ('\u{0061}', '\u{0307}', '\u{0227}'),
+    ('\u{0061}', '\u{0308}', '\u{00e4}'), ('\u{0061}', '\u{0309}', '\u{1ea3}'), ('\u{0061}', '\u{030a}', '\u{00e5}'),
+    ('\u{0061}', '\u{030c}', '\u{01ce}'), ('\u{0061}', '\u{030f}', '\u{0201}'), ('\u{0061}', '\u{0311}', '\u{0203}'),
+    ('\u{0061}', '\u{0323}', '\u{1ea1}'), ('\u{0061}', '\u{0325}', '\u{1e01}'), ('\u{0061}', '\u{0328}', '\u{0105}'),
+    ('\u{0062}', '\u{0307}', '\u{1e03}'), ('\u{0062}', '\u{0323}', '\u{1e05}'), ('\u{0062}', '\u{0331}', '\u{1e07}'),
+    ('\u{0063}', '\u{0301}', '\u{0107}'), ('\u{0063}', '\u{0302}', '\u{0109}'), ('\u{0063}', '\u{0307}', '\u{010b}'),
+    ('\u{0063}', '\u{030c}', '\u{010d}'), ('\u{0063}', '\u{0327}', '\u{00e7}'), ('\u{0064}', '\u{0307}', '\u{1e0b}'),
+    ('\u{0064}', '\u{030c}', '\u{010f}'), ('\u{0064}', '\u{0323}', '\u{1e0d}'), ('\u{0064}', '\u{0327}', '\u{1e11}'),
+    ('\u{0064}', '\u{032d}', '\u{1e13}'), ('\u{0064}', '\u{0331}', '\u{1e0f}'), ('\u{0065}', '\u{0300}', '\u{00e8}'),
+    ('\u{0065}', '\u{0301}', '\u{00e9}'), ('\u{0065}', '\u{0302}', '\u{00ea}'), ('\u{0065}', '\u{0303}', '\u{1ebd}'),
+    ('\u{0065}', '\u{0304}', '\u{0113}'), ('\u{0065}', '\u{0306}', '\u{0115}'), ('\u{0065}', '\u{0307}', '\u{0117}'),
+    ('\u{0065}', '\u{0308}', '\u{00eb}'), ('\u{0065}', '\u{0309}', '\u{1ebb}'), ('\u{0065}', '\u{030c}', '\u{011b}'),
+    ('\u{0065}', '\u{030f}', '\u{0205}'), ('\u{0065}', '\u{0311}', '\u{0207}'), ('\u{0065}', '\u{0323}', '\u{1eb9}'),
+    ('\u{0065}', '\u{0327}', '\u{0229}'), ('\u{0065}', '\u{0328}', '\u{0119}'), ('\u{0065}', '\u{032d}', '\u{1e19}'),
+    ('\u{0065}', '\u{0330}', '\u{1e1b}'), ('\u{0066}', '\u{0307}', '\u{1e1f}'), ('\u{0067}', '\u{0301}', '\u{01f5}'),
+    ('\u{0067}', '\u{0302}', '\u{011d}'), ('\u{0067}', '\u{0304}', '\u{1e21}'), ('\u{0067}', '\u{0306}', '\u{011f}'),
+    ('\u{0067}', '\u{0307}', '\u{0121}'), ('\u{0067}', '\u{030c}', '\u{01e7}'), ('\u{0067}', '\u{0327}', '\u{0123}'),
+    ('\u{0068}', '\u{0302}', '\u{0125}'), ('\u{0068}', '\u{0307}', '\u{1e23}'), ('\u{0068}', '\u{0308}', '\u{1e27}'),
+    ('\u{0068}', '\u{030c}', '\u{021f}'), ('\u{0068}', '\u{0323}', '\u{1e25}'), ('\u{0068}', '\u{0327}', '\u{1e29}'),
+    ('\u{0068}', '\u{032e}', '\u{1e2b}'), ('\u{0068}', '\u{0331}', '\u{1e96}'), ('\u{0069}', '\u{0300}', '\u{00ec}'),
+    ('\u{0069}', '\u{0301}', '\u{00ed}'), ('\u{0069}', '\u{0302}', '\u{00ee}'), ('\u{0069}', '\u{0303}', '\u{0129}'),
+    ('\u{0069}', '\u{0304}', '\u{012b}'), ('\u{0069}', '\u{0306}', '\u{012d}'), ('\u{0069}', '\u{0308}', '\u{00ef}'),
+    ('\u{0069}', '\u{0309}', '\u{1ec9}'), ('\u{0069}', '\u{030c}', '\u{01d0}'), ('\u{0069}', '\u{030f}', '\u{0209}'),
+    ('\u{0069}', '\u{0311}', '\u{020b}'), ('\u{0069}', '\u{0323}', '\u{1ecb}'), ('\u{0069}', '\u{0328}', '\u{012f}'),
+    ('\u{0069}', '\u{0330}', '\u{1e2d}'), ('\u{006a}', '\u{0302}', '\u{0135}'), ('\u{006a}', '\u{030c}', '\u{01f0}'),
+    ('\u{006b}', '\u{0301}', '\u{1e31}'), ('\u{006b}', '\u{030c}', '\u{01e9}'), ('\u{006b}', '\u{0323}', '\u{1e33}'),
+    ('\u{006b}', '\u{0327}', '\u{0137}'), ('\u{006b}', '\u{0331}', '\u{1e35}'), ('\u{006c}', '\u{0301}', '\u{013a}'),
+    ('\u{006c}', '\u{030c}', '\u{013e}'), ('\u{006c}', '\u{0323}', '\u{1e37}'), ('\u{006c}', '\u{0327}', '\u{013c}'),
+    ('\u{006c}', '\u{032d}', '\u{1e3d}'), ('\u{006c}', '\u{0331}', '\u{1e3b}'), ('\u{006d}', '\u{0301}', '\u{1e3f}'),
+    ('\u{006d}', '\u{0307}', '\u{1e41}'), ('\u{006d}', '\u{0323}', '\u{1e43}'), ('\u{006e}', '\u{0300}', '\u{01f9}'),
+    ('\u{006e}', '\u{0301}', '\u{0144}'), ('\u{006e}', '\u{0303}', '\u{00f1}'), ('\u{006e}', '\u{0307}', '\u{1e45}'),
+    ('\u{006e}', '\u{030c}', '\u{0148}'), ('\u{006e}', '\u{0323}', '\u{1e47}'), ('\u{006e}', '\u{0327}', '\u{0146}'),
+    ('\u{006e}', '\u{032d}', '\u{1e4b}'), ('\u{006e}', '\u{0331}', '\u{1e49}'), ('\u{006f}', '\u{0300}', '\u{00f2}'),
+    ('\u{006f}', '\u{0301}', '\u{00f3}'), ('\u{006f}', '\u{0302}', '\u{00f4}'), ('\u{006f}', '\u{0303}', '\u{00f5}'),
+    ('\u{006f}', '\u{0304}', '\u{014d}'), ('\u{006f}', '\u{0306}', '\u{014f}'), ('\u{006f}', '\u{0307}', '\u{022f}'),
+    ('\u{006f}', '\u{0308}', '\u{00f6}'), ('\u{006f}', '\u{0309}', '\u{1ecf}'), ('\u{006f}', '\u{030b}', '\u{0151}'),
+    ('\u{006f}', '\u{030c}', '\u{01d2}'), ('\u{006f}', '\u{030f}', '\u{020d}'), ('\u{006f}', '\u{0311}', '\u{020f}'),
+    ('\u{006f}', '\u{031b}', '\u{01a1}'), ('\u{006f}', '\u{0323}', '\u{1ecd}'), ('\u{006f}', '\u{0328}', '\u{01eb}'),
+    ('\u{0070}', '\u{0301}', '\u{1e55}'), ('\u{0070}', '\u{0307}', '\u{1e57}'), ('\u{0072}', '\u{0301}', '\u{0155}'),
+    ('\u{0072}', '\u{0307}', '\u{1e59}'), ('\u{0072}', '\u{030c}', '\u{0159}'), ('\u{0072}', '\u{030f}', '\u{0211}'),
+    ('\u{0072}', '\u{0311}', '\u{0213}'), ('\u{0072}', '\u{0323}', '\u{1e5b}'), ('\u{0072}', '\u{0327}', '\u{0157}'),
+    ('\u{0072}', '\u{0331}', '\u{1e5f}'), ('\u{0073}', '\u{0301}', '\u{015b}'), ('\u{0073}', '\u{0302}', '\u{015d}'),
+    ('\u{0073}', '\u{0307}', '\u{1e61}'), ('\u{0073}', '\u{030c}', '\u{0161}'), ('\u{0073}', '\u{0323}', '\u{1e63}'),
+    ('\u{0073}', '\u{0326}', '\u{0219}'), ('\u{0073}', '\u{0327}', '\u{015f}'), ('\u{0074}', '\u{0307}', '\u{1e6b}'),
+    ('\u{0074}', '\u{0308}', '\u{1e97}'), ('\u{0074}', '\u{030c}', '\u{0165}'), ('\u{0074}', '\u{0323}', '\u{1e6d}'),
+    ('\u{0074}', '\u{0326}', '\u{021b}'), ('\u{0074}', '\u{0327}', '\u{0163}'), ('\u{0074}', '\u{032d}', '\u{1e71}'),
+    ('\u{0074}', '\u{0331}', '\u{1e6f}'), ('\u{0075}', '\u{0300}', '\u{00f9}'), ('\u{0075}', '\u{0301}', '\u{00fa}'),
+    ('\u{0075}', '\u{0302}', '\u{00fb}'), ('\u{0075}', '\u{0303}', '\u{0169}'), ('\u{0075}', '\u{0304}', '\u{016b}'),
+    ('\u{0075}', '\u{0306}', '\u{016d}'), ('\u{0075}', '\u{0308}', '\u{00fc}'), ('\u{0075}', '\u{0309}', '\u{1ee7}'),
+    ('\u{0075}', '\u{030a}', '\u{016f}'), ('\u{0075}', '\u{030b}', '\u{0171}'), ('\u{0075}', '\u{030c}', '\u{01d4}'),
+    ('\u{0075}', '\u{030f}', '\u{0215}'), ('\u{0075}', '\u{0311}', '\u{0217}'), ('\u{0075}', '\u{031b}', '\u{01b0}'),
+    ('\u{0075}', '\u{0323}', '\u{1ee5}'), ('\u{0075}', '\u{0324}', '\u{1e73}'), ('\u{0075}', '\u{0328}', '\u{0173}'),
+    ('\u{0075}', '\u{032d}', '\u{1e77}'), ('\u{0075}', '\u{0330}', '\u{1e75}'), ('\u{0076}', '\u{0303}', '\u{1e7d}'),
+    ('\u{0076}', '\u{0323}', '\u{1e7f}'), ('\u{0077}', '\u{0300}', '\u{1e81}'), ('\u{0077}', '\u{0301}', '\u{1e83}'),
+    ('\u{0077}', '\u{0302}', '\u{0175}'), ('\u{0077}', '\u{0307}', '\u{1e87}'), ('\u{0077}', '\u{0308}', '\u{1e85}'),
+    ('\u{0077}', '\u{030a}', '\u{1e98}'), ('\u{0077}', '\u{0323}', '\u{1e89}'), ('\u{0078}', '\u{0307}', '\u{1e8b}'),
+    ('\u{0078}', '\u{0308}', '\u{1e8d}'), ('\u{0079}', '\u{0300}', '\u{1ef3}'), ('\u{0079}', '\u{0301}', '\u{00fd}'),
+    ('\u{0079}', '\u{0302}', '\u{0177}'), ('\u{0079}', '\u{0303}', '\u{1ef9}'), ('\u{0079}', '\u{0304}', '\u{0233}'),
+    ('\u{0079}', '\u{0307}', '\u{1e8f}'), ('\u{0079}', '\u{0308}', '\u{00ff}'), ('\u{0079}', '\u{0309}', '\u{1ef7}'),
+    ('\u{0079}', '\u{030a}', '\u{1e99}'), ('\u{0079}', '\u{0323}', '\u{1ef5}'), ('\u{007a}', '\u{0301}', '\u{017a}'),
+    ('\u{007a}', '\u{0302}', '\u{1e91}'), ('\u{007a}', '\u{0307}', '\u{017c}'), ('\u{007a}', '\u{030c}', '\u{017e}'),
+    ('\u{007a}', '\u{0323}', '\u{1e93}'), ('\u{007a}', '\u{0331}', '\u{1e95}'), ('\u{00a8}', '\u{0300}', '\u{1fed}'),
+    ('\u{00a8}', '\u{0301}', '\u{0385}'), ('\u{00a8}', '\u{0342}', '\u{1fc1}'), ('\u{00c2}', '\u{0300}', '\u{1ea6}'),
+    ('\u{00c2}', '\u{0301}', '\u{1ea4}'), ('\u{00c2}', '\u{0303}', '\u{1eaa}'), ('\u{00c2}', '\u{0309}', '\u{1ea8}'),
+    ('\u{00c4}', '\u{0304}', '\u{01de}'), ('\u{00c5}', '\u{0301}', '\u{01fa}'), ('\u{00c6}', '\u{0301}', '\u{01fc}'),
+    ('\u{00c6}', '\u{0304}', '\u{01e2}'), ('\u{00c7}', '\u{0301}', '\u{1e08}'), ('\u{00ca}', '\u{0300}', '\u{1ec0}'),
+    ('\u{00ca}', '\u{0301}', '\u{1ebe}'), ('\u{00ca}', '\u{0303}', '\u{1ec4}'), ('\u{00ca}', '\u{0309}', '\u{1ec2}'),
+    ('\u{00cf}', '\u{0301}', '\u{1e2e}'), ('\u{00d4}', '\u{0300}', '\u{1ed2}'), ('\u{00d4}', '\u{0301}', '\u{1ed0}'),
+    ('\u{00d4}', '\u{0303}', '\u{1ed6}'), ('\u{00d4}', '\u{0309}', '\u{1ed4}'), ('\u{00d5}', '\u{0301}', '\u{1e4c}'),
+    ('\u{00d5}', '\u{0304}', '\u{022c}'), ('\u{00d5}', '\u{0308}', '\u{1e4e}'), ('\u{00d6}', '\u{0304}', '\u{022a}'),
+    ('\u{00d8}', '\u{0301}', '\u{01fe}'), ('\u{00dc}', '\u{0300}', '\u{01db}'), ('\u{00dc}', '\u{0301}', '\u{01d7}'),
+    ('\u{00dc}', '\u{0304}', '\u{01d5}'), ('\u{00dc}', '\u{030c}', '\u{01d9}'), ('\u{00e2}', '\u{0300}', '\u{1ea7}'),
+    ('\u{00e2}', '\u{0301}', '\u{1ea5}'), ('\u{00e2}', '\u{0303}', '\u{1eab}'), ('\u{00e2}', '\u{0309}', '\u{1ea9}'),
+    ('\u{00e4}', '\u{0304}', '\u{01df}'), ('\u{00e5}', '\u{0301}', '\u{01fb}'), ('\u{00e6}', '\u{0301}', '\u{01fd}'),
+    ('\u{00e6}', '\u{0304}', '\u{01e3}'), ('\u{00e7}', '\u{0301}', '\u{1e09}'), ('\u{00ea}', '\u{0300}', '\u{1ec1}'),
+    ('\u{00ea}', '\u{0301}', '\u{1ebf}'), ('\u{00ea}', '\u{0303}', '\u{1ec5}'), ('\u{00ea}', '\u{0309}', '\u{1ec3}'),
+    ('\u{00ef}', '\u{0301}', '\u{1e2f}'), ('\u{00f4}', '\u{0300}', '\u{1ed3}'), ('\u{00f4}', '\u{0301}', '\u{1ed1}'),
+    ('\u{00f4}', '\u{0303}', '\u{1ed7}'), ('\u{00f4}', '\u{0309}', '\u{1ed5}'), ('\u{00f5}', '\u{0301}', '\u{1e4d}'),
+    ('\u{00f5}', '\u{0304}', '\u{022d}'), ('\u{00f5}', '\u{0308}', '\u{1e4f}'), ('\u{00f6}', '\u{0304}', '\u{022b}'),
+    ('\u{00f8}', '\u{0301}', '\u{01ff}'), ('\u{00fc}', '\u{0300}', '\u{01dc}'), ('\u{00fc}', '\u{0301}', '\u{01d8}'),
+    ('\u{00fc}', '\u{0304}', '\u{01d6}'), ('\u{00fc}', '\u{030c}', '\u{01da}'), ('\u{0102}', '\u{0300}', '\u{1eb0}'),
+    ('\u{0102}', '\u{0301}', '\u{1eae}'), ('\u{0102}', '\u{0303}', '\u{1eb4}'), ('\u{0102}', '\u{0309}', '\u{1eb2}'),
+    ('\u{0103}', '\u{0300}', '\u{1eb1}'), ('\u{0103}', '\u{0301}', '\u{1eaf}'), ('\u{0103}', '\u{0303}', '\u{1eb5}'),
+    ('\u{0103}', '\u{0309}', '\u{1eb3}'), ('\u{0112}', '\u{0300}', '\u{1e14}'), ('\u{0112}', '\u{0301}', '\u{1e16}'),
+    ('\u{0113}', '\u{0300}', '\u{1e15}'), ('\u{0113}', '\u{0301}', '\u{1e17}'), ('\u{014c}', '\u{0300}', '\u{1e50}'),
+    ('\u{014c}', '\u{0301}', '\u{1e52}'), ('\u{014d}', '\u{0300}', '\u{1e51}'), ('\u{014d}', '\u{0301}', '\u{1e53}'),
+    ('\u{015a}', '\u{0307}', '\u{1e64}'), ('\u{015b}', '\u{0307}', '\u{1e65}'), ('\u{0160}', '\u{0307}', '\u{1e66}'),
+    ('\u{0161}', '\u{0307}', '\u{1e67}'), ('\u{0168}', '\u{0301}', '\u{1e78}'), ('\u{0169}', '\u{0301}', '\u{1e79}'),
+    ('\u{016a}', '\u{0308}', '\u{1e7a}'), ('\u{016b}', '\u{0308}', '\u{1e7b}'), ('\u{017f}', '\u{0307}', '\u{1e9b}'),
+    ('\u{01a0}', '\u{0300}', '\u{1edc}'), ('\u{01a0}', '\u{0301}', '\u{1eda}'), ('\u{01a0}', '\u{0303}', '\u{1ee0}'),
+    ('\u{01a0}', '\u{0309}', '\u{1ede}'), ('\u{01a0}', '\u{0323}', '\u{1ee2}'), ('\u{01a1}', '\u{0300}', '\u{1edd}'),
+    ('\u{01a1}', '\u{0301}', '\u{1edb}'), ('\u{01a1}', '\u{0303}', '\u{1ee1}'), ('\u{01a1}', '\u{0309}', '\u{1edf}'),
+    ('\u{01a1}', '\u{0323}', '\u{1ee3}'), ('\u{01af}', '\u{0300}', '\u{1eea}'), ('\u{01af}', '\u{0301}', '\u{1ee8}'),
+    ('\u{01af}', '\u{0303}', '\u{1eee}'), ('\u{01af}', '\u{0309}', '\u{1eec}'), ('\u{01af}', '\u{0323}', '\u{1ef0}'),
+    ('\u{01b0}', '\u{0300}', '\u{1eeb}'), ('\u{01b0}', '\u{0301}', '\u{1ee9}'), ('\u{01b0}', '\u{0303}', '\u{1eef}'),
+    ('\u{01b0}', '\u{0309}', '\u{1eed}'), ('\u{01b0}', '\u{0323}', '\u{1ef1}'), ('\u{01b7}', '\u{030c}', '\u{01ee}'),
+    ('\u{01ea}', '\u{0304}', '\u{01ec}'), ('\u{01eb}', '\u{0304}', '\u{01ed}'), ('\u{0226}', '\u{0304}', '\u{01e0}'),
+    ('\u{0227}', '\u{0304}', '\u{01e1}'), ('\u{0228}', '\u{0306}', '\u{1e1c}'), ('\u{0229}', '\u{0306}', '\u{1e1d}'),
+    ('\u{022e}', '\u{0304}', '\u{0230}'), ('\u{022f}', '\u{0304}', '\u{0231}'), ('\u{0292}', '\u{030c}', '\u{01ef}'),
+    ('\u{0391}', '\u{0300}', '\u{1fba}'), ('\u{0391}', '\u{0301}', '\u{0386}'), ('\u{0391}', '\u{0304}', '\u{1fb9}'),
+    ('\u{0391}', '\u{0306}', '\u{1fb8}'), ('\u{0391}', '\u{0313}', '\u{1f08}'), ('\u{0391}', '\u{0314}', '\u{1f09}'),
+    ('\u{0391}', '\u{0345}', '\u{1fbc}'), ('\u{0395}', '\u{0300}', '\u{1fc8}'), ('\u{0395}', '\u{0301}', '\u{0388}'),
+    ('\u{0395}', '\u{0313}', '\u{1f18}'), ('\u{0395}', '\u{0314}', '\u{1f19}'), ('\u{0397}', '\u{0300}', '\u{1fca}'),
+    ('\u{0397}', '\u{0301}', '\u{0389}'), ('\u{0397}', '\u{0313}', '\u{1f28}'), ('\u{0397}', '\u{0314}', '\u{1f29}'),
+    ('\u{0397}', '\u{0345}', '\u{1fcc}'), ('\u{0399}', '\u{0300}', '\u{1fda}'), ('\u{0399}', '\u{0301}', '\u{038a}'),
+    ('\u{0399}', '\u{0304}', '\u{1fd9}'), ('\u{0399}', '\u{0306}', '\u{1fd8}'), ('\u{0399}', '\u{0308}', '\u{03aa}'),
+    ('\u{0399}', '\u{0313}', '\u{1f38}'), ('\u{0399}', '\u{0314}', '\u{1f39}'), ('\u{039f}', '\u{0300}', '\u{1ff8}'),
+    ('\u{039f}', '\u{0301}', '\u{038c}'), ('\u{039f}', '\u{0313}', '\u{1f48}'), ('\u{039f}', '\u{0314}', '\u{1f49}'),
+    ('\u{03a1}', '\u{0314}', '\u{1fec}'), ('\u{03a5}', '\u{0300}', '\u{1fea}'), ('\u{03a5}', '\u{0301}', '\u{038e}'),
+    ('\u{03a5}', '\u{0304}', '\u{1fe9}'), ('\u{03a5}', '\u{0306}', '\u{1fe8}'), ('\u{03a5}', '\u{0308}', '\u{03ab}'),
+    ('\u{03a5}', '\u{0314}', '\u{1f59}'), ('\u{03a9}', '\u{0300}', '\u{1ffa}'), ('\u{03a9}', '\u{0301}', '\u{038f}'),
+    ('\u{03a9}', '\u{0313}', '\u{1f68}'), ('\u{03a9}', '\u{0314}', '\u{1f69}'), ('\u{03a9}', '\u{0345}', '\u{1ffc}'),
+    ('\u{03ac}', '\u{0345}', '\u{1fb4}'), ('\u{03ae}', '\u{0345}', '\u{1fc4}'), ('\u{03b1}', '\u{0300}', '\u{1f70}'),
+    ('\u{03b1}', '\u{0301}', '\u{03ac}'), ('\u{03b1}', '\u{0304}', '\u{1fb1}'), ('\u{03b1}', '\u{0306}', '\u{1fb0}'),
+    ('\u{03b1}', '\u{0313}', '\u{1f00}'), ('\u{03b1}', '\u{0314}', '\u{1f01}'), ('\u{03b1}', '\u{0342}', '\u{1fb6}'),
+    ('\u{03b1}', '\u{0345}', '\u{1fb3}'), ('\u{03b5}', '\u{0300}', '\u{1f72}'), ('\u{03b5}', '\u{0301}', '\u{03ad}'),
+    ('\u{03b5}', '\u{0313}', '\u{1f10}'), ('\u{03b5}', '\u{0314}', '\u{1f11}'), ('\u{03b7}', '\u{0300}', '\u{1f74}'),
+    ('\u{03b7}', '\u{0301}', '\u{03ae}'), ('\u{03b7}', '\u{0313}', '\u{1f20}'), ('\u{03b7}', '\u{0314}', '\u{1f21}'),
+    ('\u{03b7}', '\u{0342}', '\u{1fc6}'), ('\u{03b7}', '\u{0345}', '\u{1fc3}'), ('\u{03b9}', '\u{0300}', '\u{1f76}'),
+    ('\u{03b9}', '\u{0301}', '\u{03af}'), ('\u{03b9}', '\u{0304}', '\u{1fd1}'), ('\u{03b9}', '\u{0306}', '\u{1fd0}'),
+    ('\u{03b9}', '\u{0308}', '\u{03ca}'), ('\u{03b9}', '\u{0313}', '\u{1f30}'), ('\u{03b9}', '\u{0314}', '\u{1f31}'),
+    ('\u{03b9}', '\u{0342}', '\u{1fd6}'), ('\u{03bf}', '\u{0300}', '\u{1f78}'), ('\u{03bf}', '\u{0301}', '\u{03cc}'),
+    ('\u{03bf}', '\u{0313}', '\u{1f40}'), ('\u{03bf}', '\u{0314}', '\u{1f41}'), ('\u{03c1}', '\u{0313}', '\u{1fe4}'),
+    ('\u{03c1}', '\u{0314}', '\u{1fe5}'), ('\u{03c5}', '\u{0300}', '\u{1f7a}'), ('\u{03c5}', '\u{0301}', '\u{03cd}'),
+    ('\u{03c5}', '\u{0304}', '\u{1fe1}'), ('\u{03c5}', '\u{0306}', '\u{1fe0}'), ('\u{03c5}', '\u{0308}', '\u{03cb}'),
+    ('\u{03c5}', '\u{0313}', '\u{1f50}'), ('\u{03c5}', '\u{0314}', '\u{1f51}'), ('\u{03c5}', '\u{0342}', '\u{1fe6}'),
+    ('\u{03c9}', '\u{0300}', '\u{1f7c}'), ('\u{03c9}', '\u{0301}', '\u{03ce}'), ('\u{03c9}', '\u{0313}', '\u{1f60}'),
+    ('\u{03c9}', '\u{0314}', '\u{1f61}'), ('\u{03c9}', '\u{0342}', '\u{1ff6}'), ('\u{03c9}', '\u{0345}', '\u{1ff3}'),
+    ('\u{03ca}', '\u{0300}', '\u{1fd2}'), ('\u{03ca}', '\u{0301}', '\u{0390}'), ('\u{03ca}', '\u{0342}', '\u{1fd7}'),
+    ('\u{03cb}', '\u{0300}', '\u{1fe2}'), ('\u{03cb}', '\u{0301}', '\u{03b0}'), ('\u{03cb}', '\u{0342}', '\u{1fe7}'),
+    ('\u{03ce}', '\u{0345}', '\u{1ff4}'), ('\u{03d2}', '\u{0301}', '\u{03d3}'), ('\u{03d2}', '\u{0308}', '\u{03d4}'),
+    ('\u{0406}', '\u{0308}', '\u{0407}'), ('\u{0410}', '\u{0306}', '\u{04d0}'), ('\u{0410}', '\u{0308}', '\u{04d2}'),
+    ('\u{0413}', '\u{0301}', '\u{0403}'), ('\u{0415}', '\u{0300}', '\u{0400}'), ('\u{0415}', '\u{0306}', '\u{04d6}'),
+    ('\u{0415}', '\u{0308}', '\u{0401}'), ('\u{0416}', '\u{0306}', '\u{04c1}'), ('\u{0416}', '\u{0308}', '\u{04dc}'),
+    ('\u{0417}', '\u{0308}', '\u{04de}'), ('\u{0418}', '\u{0300}', '\u{040d}'), ('\u{0418}', '\u{0304}', '\u{04e2}'),
+    ('\u{0418}', '\u{0306}', '\u{0419}'), ('\u{0418}', '\u{0308}', '\u{04e4}'), ('\u{041a}', '\u{0301}', '\u{040c}'),
+    ('\u{041e}', '\u{0308}', '\u{04e6}'), ('\u{0423}', '\u{0304}', '\u{04ee}'), ('\u{0423}', '\u{0306}', '\u{040e}'),
+    ('\u{0423}', '\u{0308}', '\u{04f0}'), ('\u{0423}', '\u{030b}', '\u{04f2}'), ('\u{0427}', '\u{0308}', '\u{04f4}'),
+    ('\u{042b}', '\u{0308}', '\u{04f8}'), ('\u{042d}', '\u{0308}', '\u{04ec}'), ('\u{0430}', '\u{0306}', '\u{04d1}'),
+    ('\u{0430}', '\u{0308}', '\u{04d3}'), ('\u{0433}', '\u{0301}', '\u{0453}'), ('\u{0435}', '\u{0300}', '\u{0450}'),
+    ('\u{0435}', '\u{0306}', '\u{04d7}'), ('\u{0435}', '\u{0308}', '\u{0451}'), ('\u{0436}', '\u{0306}', '\u{04c2}'),
+    ('\u{0436}', '\u{0308}', '\u{04dd}'), ('\u{0437}', '\u{0308}', '\u{04df}'), ('\u{0438}', '\u{0300}', '\u{045d}'),
+    ('\u{0438}', '\u{0304}', '\u{04e3}'), ('\u{0438}', '\u{0306}', '\u{0439}'), ('\u{0438}', '\u{0308}', '\u{04e5}'),
+    ('\u{043a}', '\u{0301}', '\u{045c}'), ('\u{043e}', '\u{0308}', '\u{04e7}'), ('\u{0443}', '\u{0304}', '\u{04ef}'),
+    ('\u{0443}', '\u{0306}', '\u{045e}'), ('\u{0443}', '\u{0308}', '\u{04f1}'), ('\u{0443}', '\u{030b}', '\u{04f3}'),
+    ('\u{0447}', '\u{0308}', '\u{04f5}'), ('\u{044b}', '\u{0308}', '\u{04f9}'), ('\u{044d}', '\u{0308}', '\u{04ed}'),
+    ('\u{0456}', '\u{0308}', '\u{0457}'), ('\u{0474}', '\u{030f}', '\u{0476}'), ('\u{0475}', '\u{030f}', '\u{0477}'),
+    ('\u{04d8}', '\u{0308}', '\u{04da}'), ('\u{04d9}', '\u{0308}', '\u{04db}'), ('\u{04e8}', '\u{0308}', '\u{04ea}'),
+    ('\u{04e9}', '\u{0308}', '\u{04eb}'), ('\u{1e36}', '\u{0304}', '\u{1e38}'), ('\u{1e37}', '\u{0304}', '\u{1e39}'),
+    ('\u{1e5a}', '\u{0304}', '\u{1e5c}'), ('\u{1e5b}', '\u{0304}', '\u{1e5d}'), ('\u{1e62}', '\u{0307}', '\u{1e68}'),
+    ('\u{1e63}', '\u{0307}', '\u{1e69}'), ('\u{1ea0}', '\u{0302}', '\u{1eac}'), ('\u{1ea0}', '\u{0306}', '\u{1eb6}'),
+    ('\u{1ea1}', '\u{0302}', '\u{1ead}'), ('\u{1ea1}', '\u{0306}', '\u{1eb7}'), ('\u{1eb8}', '\u{0302}', '\u{1ec6}'),
+    ('\u{1eb9}', '\u{0302}', '\u{1ec7}'), ('\u{1ecc}', '\u{0302}', '\u{1ed8}'), ('\u{1ecd}', '\u{0302}', '\u{1ed9}'),
+    ('\u{1f00}', '\u{0300}', '\u{1f02}'), ('\u{1f00}', '\u{0301}', '\u{1f04}'), ('\u{1f00}', '\u{0342}', '\u{1f06}'),
+    ('\u{1f00}', '\u{0345}', '\u{1f80}'), ('\u{1f01}', '\u{0300}', '\u{1f03}'), ('\u{1f01}', '\u{0301}', '\u{1f05}'),
+    ('\u{1f01}', '\u{0342}', '\u{1f07}'), ('\u{1f01}', '\u{0345}', '\u{1f81}'), ('\u{1f02}', '\u{0345}', '\u{1f82}'),
+    ('\u{1f03}', '\u{0345}', '\u{1f83}'), ('\u{1f04}', '\u{0345}', '\u{1f84}'), ('\u{1f05}', '\u{0345}', '\u{1f85}'),
+    ('\u{1f06}', '\u{0345}', '\u{1f86}'), ('\u{1f07}', '\u{0345}', '\u{1f87}'), ('\u{1f08}', '\u{0300}', '\u{1f0a}'),
+    ('\u{1f08}', '\u{0301}', '\u{1f0c}'), ('\u{1f08}', '\u{0342}', '\u{1f0e}'), ('\u{1f08}', '\u{0345}', '\u{1f88}'),
+    ('\u{1f09}', '\u{0300}', '\u{1f0b}'), ('\u{1f09}', '\u{0301}', '\u{1f0d}'), ('\u{1f09}', '\u{0342}', '\u{1f0f}'),
+    ('\u{1f09}', '\u{0345}', '\u{1f89}'), ('\u{1f0a}', '\u{0345}', '\u{1f8a}'), ('\u{1f0b}', '\u{0345}', '\u{1f8b}'),
+    ('\u{1f0c}', '\u{0345}', '\u{1f8c}'), ('\u{1f0d}', '\u{0345}', '\u{1f8d}'), ('\u{1f0e}', '\u{0345}', '\u{1f8e}'),
+    ('\u{1f0f}', '\u{0345}', '\u{1f8f}'), ('\u{1f10}', '\u{0300}', '\u{1f12}'), ('\u{1f10}', '\u{0301}', '\u{1f14}'),
+    ('\u{1f11}', '\u{0300}', '\u{1f13}'), ('\u{1f11}', '\u{0301}', '\u{1f15}'), ('\u{1f18}', '\u{0300}', '\u{1f1a}'),
+    ('\u{1f18}', '\u{0301}', '\u{1f1c}'), ('\u{1f19}', '\u{0300}', '\u{1f1b}'), ('\u{1f19}', '\u{0301}', '\u{1f1d}'),
+    ('\u{1f20}', '\u{0300}', '\u{1f22}'), ('\u{1f20}', '\u{0301}', '\u{1f24}'), ('\u{1f20}', '\u{0342}', '\u{1f26}'),
+    ('\u{1f20}', '\u{0345}', '\u{1f90}'), ('\u{1f21}', '\u{0300}', '\u{1f23}'), ('\u{1f21}', '\u{0301}', '\u{1f25}'),
+    ('\u{1f21}', '\u{0342}', '\u{1f27}'), ('\u{1f21}', '\u{0345}', '\u{1f91}'), ('\u{1f22}', '\u{0345}', '\u{1f92}'),
+    ('\u{1f23}', '\u{0345}', '\u{1f93}'), ('\u{1f24}', '\u{0345}', '\u{1f94}'), ('\u{1f25}', '\u{0345}', '\u{1f95}'),
+    ('\u{1f26}', '\u{0345}', '\u{1f96}'), ('\u{1f27}', '\u{0345}', '\u{1f97}'), ('\u{1f28}', '\u{0300}', '\u{1f2a}'),
+    ('\u{1f28}', '\u{0301}', '\u{1f2c}'), ('\u{1f28}', '\u{0342}', '\u{1f2e}'), ('\u{1f28}', '\u{0345}', '\u{1f98}'),
+    ('\u{1f29}', '\u{0300}', '\u{1f2b}'), ('\u{1f29}', '\u{0301}', '\u{1f2d}'), ('\u{1f29}', '\u{0342}', '\u{1f2f}'),
+    ('\u{1f29}', '\u{0345}', '\u{1f99}'), ('\u{1f2a}', '\u{0345}', '\u{1f9a}'), ('\u{1f2b}', '\u{0345}', '\u{1f9b}'),
+    ('\u{1f2c}', '\u{0345}', '\u{1f9c}'), ('\u{1f2d}', '\u{0345}', '\u{1f9d}'), ('\u{1f2e}', '\u{0345}', '\u{1f9e}'),
+    ('\u{1f2f}', '\u{0345}', '\u{1f9f}'), ('\u{1f30}', '\u{0300}', '\u{1f32}'), ('\u{1f30}', '\u{0301}', '\u{1f34}'),
+    ('\u{1f30}', '\u{0342}', '\u{1f36}'), ('\u{1f31}', '\u{0300}', '\u{1f33}'), ('\u{1f31}', '\u{0301}', '\u{1f35}'),
+    ('\u{1f31}', '\u{0342}', '\u{1f37}'), ('\u{1f38}', '\u{0300}', '\u{1f3a}'), ('\u{1f38}', '\u{0301}', '\u{1f3c}'),
+    ('\u{1f38}', '\u{0342}', '\u{1f3e}'), ('\u{1f39}', '\u{0300}', '\u{1f3b}'), ('\u{1f39}', '\u{0301}', '\u{1f3d}'),
+    ('\u{1f39}', '\u{0342}', '\u{1f3f}'), ('\u{1f40}', '\u{0300}', '\u{1f42}'), ('\u{1f40}', '\u{0301}', '\u{1f44}'),
+    ('\u{1f41}', '\u{0300}', '\u{1f43}'), ('\u{1f41}', '\u{0301}', '\u{1f45}'), ('\u{1f48}', '\u{0300}', '\u{1f4a}'),
+    ('\u{1f48}', '\u{0301}', '\u{1f4c}'), ('\u{1f49}', '\u{0300}', '\u{1f4b}'), ('\u{1f49}', '\u{0301}', '\u{1f4d}'),
+    ('\u{1f50}', '\u{0300}', '\u{1f52}'), ('\u{1f50}', '\u{0301}', '\u{1f54}'), ('\u{1f50}', '\u{0342}', '\u{1f56}'),
+    ('\u{1f51}', '\u{0300}', '\u{1f53}'), ('\u{1f51}', '\u{0301}', '\u{1f55}'), ('\u{1f51}', '\u{0342}', '\u{1f57}'),
+    ('\u{1f59}', '\u{0300}', '\u{1f5b}'), ('\u{1f59}', '\u{0301}', '\u{1f5d}'), ('\u{1f59}', '\u{0342}', '\u{1f5f}'),
+    ('\u{1f60}', '\u{0300}', '\u{1f62}'), ('\u{1f60}', '\u{0301}', '\u{1f64}'), ('\u{1f60}', '\u{0342}', '\u{1f66}'),
+    ('\u{1f60}', '\u{0345}', '\u{1fa0}'), ('\u{1f61}', '\u{0300}', '\u{1f63}'), ('\u{1f61}', '\u{0301}', '\u{1f65}'),
+    ('\u{1f61}', '\u{0342}', '\u{1f67}'), ('\u{1f61}', '\u{0345}', '\u{1fa1}'), ('\u{1f62}', '\u{0345}', '\u{1fa2}'),
+    ('\u{1f63}', '\u{0345}', '\u{1fa3}'), ('\u{1f64}', '\u{0345}', '\u{1fa4}'), ('\u{1f65}', '\u{0345}', '\u{1fa5}'),
+    ('\u{1f66}', '\u{0345}', '\u{1fa6}'), ('\u{1f67}', '\u{0345}', '\u{1fa7}'), ('\u{1f68}', '\u{0300}', '\u{1f6a}'),
+    ('\u{1f68}', '\u{0301}', '\u{1f6c}'), ('\u{1f68}', '\u{0342}', '\u{1f6e}'), ('\u{1f68}', '\u{0345}', '\u{1fa8}'),
+    ('\u{1f69}', '\u{0300}', '\u{1f6b}'), ('\u{1f69}', '\u{0301}', '\u{1f6d}'), ('\u{1f69}', '\u{0342}', '\u{1f6f}'),
+    ('\u{1f69}', '\u{0345}', '\u{1fa9}'), ('\u{1f6a}', '\u{0345}', '\u{1faa}'), ('\u{1f6b}', '\u{0345}', '\u{1fab}'),
+    ('\u{1f6c}', '\u{0345}', '\u{1fac}'), ('\u{1f6d}', '\u{0345}', '\u{1fad}'), ('\u{1f6e}', '\u{0345}', '\u{1fae}'),
+    ('\u{1f6f}', '\u{0345}', '\u{1faf}'), ('\u{1f70}', '\u{0345}', '\u{1fb2}'), ('\u{1f74}', '\u{0345}', '\u{1fc2}'),
+    ('\u{1f7c}', '\u{0345}', '\u{1ff2}'), ('\u{1fb6}', '\u{0345}', '\u{1fb7}'), ('\u{1fbf}', '\u{0300}', '\u{1fcd}'),
+    ('\u{1fbf}', '\u{0301}', '\u{1fce}'), ('\u{1fbf}', '\u{0342}', '\u{1fcf}'), ('\u{1fc6}', '\u{0345}', '\u{1fc7}'),
+    ('\u{1ff6}', '\u{0345}', '\u{1ff7}'), ('\u{1ffe}', '\u{0300}', '\u{1fdd}'), ('\u{1ffe}', '\u{0301}', '\u{1fde}'),
+    ('\u{1ffe}', '\u{0342}', '\u{1fdf}'),
+];
+
+// Canonical combining classes of the combining marks.
+pub(crate) const COMBINING_CLASSES: &[(char, u8)] = &[
+    ('\u{0300}', 230), ('\u{0301}', 230), ('\u{0302}', 230), ('\u{0303}', 230), ('\u{0304}', 230), ('\u{0305}', 230),
+    ('\u{0306}', 230), ('\u{0307}', 230), ('\u{0308}', 230), ('\u{0309}', 230), ('\u{030a}', 230), ('\u{030b}', 230),
+    ('\u{030c}', 230), ('\u{030d}', 230), ('\u{030e}', 230), ('\u{030f}', 230), ('\u{0310}', 230), ('\u{0311}', 230),
+    ('\u{0312}', 230), ('\u{0313}', 230), ('\u{0314}', 230), ('\u{0315}', 232), ('\u{0316}', 220), ('\u{0317}', 220),
+    ('\u{0318}', 220), ('\u{0319}', 220), ('\u{031a}', 232), ('\u{031b}', 216), ('\u{031c}', 220), ('\u{031d}', 220),
+    ('\u{031e}', 220), ('\u{031f}', 220), ('\u{0320}', 220), ('\u{0321}', 202), ('\u{0322}', 202), ('\u{0323}', 220),
+    ('\u{0324}', 220), ('\u{0325}', 220), ('\u{0326}', 220), ('\u{0327}', 202), ('\u{0328}', 202), ('\u{0329}', 220),
+    ('\u{032a}', 220), ('\u{032b}', 220), ('\u{032c}', 220), ('\u{032d}', 220), ('\u{032e}', 220), ('\u{032f}', 220),
+    ('\u{0330}', 220), ('\u{0331}', 220), ('\u{0332}', 220), ('\u{0333}', 220), ('\u{0334}', 1), ('\u{0335}', 1),
+    ('\u{0336}', 1), ('\u{0337}', 1), ('\u{0338}', 1), ('\u{0339}', 220), ('\u{033a}', 220), ('\u{033b}', 220),
+    ('\u{033c}', 220), ('\u{033d}', 230), ('\u{033e}', 230), ('\u{033f}', 230), ('\u{0340}', 230), ('\u{0341}', 230),
+    ('\u{0342}', 230), ('\u{0343}', 230), ('\u{0344}', 230), ('\u{0345}', 240), ('\u{0346}', 230), ('\u{0347}', 220),
+    ('\u{0348}', 220), ('\u{0349}', 220), ('\u{034a}', 230), ('\u{034b}', 230), ('\u{034c}', 230), ('\u{034d}', 220),
+    ('\u{034e}', 220), ('\u{0350}', 230), ('\u{0351}', 230), ('\u{0352}', 230), ('\u{0353}', 220), ('\u{0354}', 220),
+    ('\u{0355}', 220), ('\u{0356}', 220), ('\u{0357}', 230), ('\u{0358}', 232), ('\u{0359}', 220), ('\u{035a}', 220),
+    ('\u{035b}', 230), ('\u{035c}', 233), ('\u{035d}', 234), ('\u{035e}', 234), ('\u{035f}', 233), ('\u{0360}', 234),
+    ('\u{0361}', 234), ('\u{0362}', 233), ('\u{0363}', 230), ('\u{0364}', 230), ('\u{0365}', 230), ('\u{0366}', 230),
+    ('\u{0367}', 230), ('\u{0368}', 230), ('\u{0369}', 230), ('\u{036a}', 230), ('\u{036b}', 230), ('\u{036c}', 230),
+    ('\u{036d}', 230), ('\u{036e}', 230), ('\u{036f}', 230), ('\u{0483}', 230), ('\u{0484}', 230), ('\u{0485}', 230),
+    ('\u{0486}', 230), ('\u{0487}', 230), ('\u{1ab0}', 230), ('\u{1ab1}', 230), ('\u{1ab2}', 230), ('\u{1ab3}', 230),
+    ('\u{1ab4}', 230), ('\u{1ab5}', 220), ('\u{1ab6}', 220), ('\u{1ab7}', 220), ('\u{1ab8}', 220), ('\u{1ab9}', 220),
+    ('\u{1aba}', 220), ('\u{1abb}', 230), ('\u{1abc}', 230), ('\u{1abd}', 220), ('\u{1abf}', 220), ('\u{1ac0}', 220),
+    ('\u{1ac1}', 230), ('\u{1ac2}', 230), ('\u{1ac3}', 220), ('\u{1ac4}', 220), ('\u{1ac5}', 230), ('\u{1ac6}', 230),
+    ('\u{1ac7}', 230), ('\u{1ac8}', 230), ('\u{1ac9}', 230), ('\u{1aca}', 220), ('\u{1acb}', 230), ('\u{1acc}', 230),
+    ('\u{1acd}', 230), ('\u{1ace}', 230), ('\u{1dc0}', 230), ('\u{1dc1}', 230), ('\u{1dc2}', 220), ('\u{1dc3}', 230),
+    ('\u{1dc4}', 230), ('\u{1dc5}', 230), ('\u{1dc6}', 230), ('\u{1dc7}', 230), ('\u{1dc8}', 230), ('\u{1dc9}', 230),
+    ('\u{1dca}', 220), ('\u{1dcb}', 230), ('\u{1dcc}', 230), ('\u{1dcd}', 234), ('\u{1dce}', 214), ('\u{1dcf}', 220),
+    ('\u{1dd0}', 202), ('\u{1dd1}', 230), ('\u{1dd2}', 230), ('\u{1dd3}', 230), ('\u{1dd4}', 230), ('\u{1dd5}', 230),
+    ('\u{1dd6}', 230), ('\u{1dd7}', 230), ('\u{1dd8}', 230), ('\u{1dd9}', 230), ('\u{1dda}', 230), ('\u{1ddb}', 230),
+    ('\u{1ddc}', 230), ('\u{1ddd}', 230), ('\u{1dde}', 230), ('\u{1ddf}', 230), ('\u{1de0}', 230), ('\u{1de1}', 230),
+    ('\u{1de2}', 230), ('\u{1de3}', 230), ('\u{1de4}', 230), ('\u{1de5}', 230), ('\u{1de6}', 230), ('\u{1de7}', 230),
+    ('\u{1de8}', 230), ('\u{1de9}', 230), ('\u{1dea}', 230), ('\u{1deb}', 230), ('\u{1dec}', 230), ('\u{1ded}', 230),
+    ('\u{1dee}', 230), ('\u{1def}', 230), ('\u{1df0}', 230), ('\u{1df1}', 230), ('\u{1df2}', 230), ('\u{1df3}', 230),
+    ('\u{1df4}', 230), ('\u{1df5}', 230), ('\u{1df6}', 232), ('\u{1df7}', 228), ('\u{1df8}', 228), ('\u{1df9}', 220),
+    ('\u{1dfa}', 218), ('\u{1dfb}', 230), ('\u{1dfc}', 233), ('\u{1dfd}', 220), ('\u{1dfe}', 230), ('\u{1dff}', 220),
+    ('\u{20d0}', 230), ('\u{20d1}', 230), ('\u{20d2}', 1), ('\u{20d3}', 1), ('\u{20d4}', 230), ('\u{20d5}', 230),
+    ('\u{20d6}', 230), ('\u{20d7}', 230), ('\u{20d8}', 1), ('\u{20d9}', 1), ('\u{20da}', 1), ('\u{20db}', 230),
+    ('\u{20dc}', 230), ('\u{20e1}', 230), ('\u{20e5}', 1), ('\u{20e6}', 1), ('\u{20e7}', 230), ('\u{20e8}', 220),
+    ('\u{20e9}', 230), ('\u{20ea}', 1), ('\u{20eb}', 1), ('\u{20ec}', 220), ('\u{20ed}', 220), ('\u{20ee}', 220),
+    ('\u{20ef}', 220), ('\u{20f0}', 230),
+];
+
+// Canonical decompositions into a single character over the same blocks,
+// which composition never produces.
+pub(crate) const SINGLETONS: &[(char, char)] = &[
+    ('\u{0340}', '\u{0300}'), ('\u{0341}', '\u{0301}'), ('\u{0343}', '\u{0313}'), ('\u{0374}', '\u{02b9}'),
+    ('\u{037e}', '\u{003b}'), ('\u{0387}', '\u{00b7}'), ('\u{1f71}', '\u{03ac}'), ('\u{1f73}', '\u{03ad}'),
+    ('\u{1f75}', '\u{03ae}'), ('\u{1f77}', '\u{03af}'), ('\u{1f79}', '\u{03cc}'), ('\u{1f7b}', '\u{03cd}'),
+    ('\u{1f7d}', '\u{03ce}'), ('\u{1fbb}', '\u{0386}'), ('\u{1fbe}', '\u{03b9}'), ('\u{1fc9}', '\u{0388}'),
+    ('\u{1fcb}', '\u{0389}'), ('\u{1fd3}', '\u{0390}'), ('\u{1fdb}', '\u{038a}'), ('\u{1fe3}', '\u{03b0}'),
+    ('\u{1feb}', '\u{038e}'), ('\u{1fee}', '\u{0385}'), ('\u{1fef}', '\u{0060}'), ('\u{1ff9}', '\u{038c}'),
+    ('\u{1ffb}', '\u{038f}'), ('\u{1ffd}', '\u{00b4}'),
+];
+
+// Canonical decompositions of a combining mark into two marks over the same
+// blocks, which composition never produces either.
+pub(crate) const MARK_DECOMPOSITIONS: &[(char, char, char)] = &[
+    ('\u{0344}', '\u{0308}', '\u{0301}'),
+];
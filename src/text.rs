@@ -0,0 +1,313 @@
+use std::borrow::Cow;
+
+use crate::merge::CountMerge;
+use crate::{intersect2, Analyzer, DocID, Index, Posting, T};
+
+/// CaseFold selects how the index folds letter case. It is applied
+/// identically to documents and queries.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CaseFold {
+    #[default]
+    None,
+    /// fold A-Z to a-z
+    Ascii,
+    /// fold every character with a one-to-one lowercase mapping; this is
+    /// Unicode simple case folding apart from a handful of characters
+    Unicode,
+}
+
+impl CaseFold {
+    pub fn apply<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        match self {
+            CaseFold::None => Cow::Borrowed(s),
+            CaseFold::Ascii => {
+                if s.bytes().any(|b| b.is_ascii_uppercase()) {
+                    Cow::Owned(s.to_ascii_lowercase())
+                } else {
+                    Cow::Borrowed(s)
+                }
+            }
+            CaseFold::Unicode => {
+                if s.chars().all(|c| fold_char(c) == c) {
+                    Cow::Borrowed(s)
+                } else {
+                    Cow::Owned(s.chars().map(fold_char).collect())
+                }
+            }
+        }
+    }
+}
+
+// FoldChar maps c to its simple case folding. Characters whose lowercase
+// form is several characters long have no simple folding and stay as is.
+fn fold_char(c: char) -> char {
+    if c.is_ascii() {
+        return c.to_ascii_lowercase();
+    }
+
+    // final sigma lowercases to itself but folds with the other sigmas
+    if c == 'ς' {
+        return 'σ';
+    }
+
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(l), None) => l,
+        _ => c,
+    }
+}
+
+/// Unit selects what a trigram is made of
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Unit {
+    /// three consecutive bytes of UTF-8
+    #[default]
+    Byte,
+    /// three consecutive code points, which is far more selective for
+    /// scripts whose characters take several bytes
+    Char,
+}
+
+impl Index {
+    pub fn analyzer(&self) -> &dyn Analyzer {
+        self.analyzer.as_ref()
+    }
+
+    // Normalize maps s to the text that is actually indexed or queried
+    pub(crate) fn normalize<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        self.analyzer.normalize(s)
+    }
+
+    // ExtractDocument appends the trigrams of document s to ts
+    pub(crate) fn extract_document(&self, s: &str, ts: &mut Vec<T>) {
+        self.analyzer.document(&self.normalize(s), ts);
+    }
+
+    // ExtractQuery returns the unique trigrams of query s
+    pub(crate) fn extract_query(&self, s: &str) -> Vec<T> {
+        self.analyzer.query(&self.normalize(s))
+    }
+}
+
+// the most gram positions query_ignore_case intersects; later positions
+// only narrow the candidates further, so skipping them is safe
+const IGNORE_CASE_MAX_TRIGRAMS: usize = 16;
+
+// the most case variants of a single gram query_ignore_case looks up;
+// positions with more are skipped
+const IGNORE_CASE_MAX_VARIANTS: usize = 64;
+
+// ByteVariants returns the grams of every combination of ASCII letter
+// case of the bytes in w, or None if there are more than
+// IGNORE_CASE_MAX_VARIANTS
+fn byte_variants(w: &[u8]) -> Option<Vec<T>> {
+    let mut variants = vec![w.to_vec()];
+
+    for i in 0..w.len() {
+        if !w[i].is_ascii_alphabetic() {
+            continue;
+        }
+        if variants.len() * 2 > IGNORE_CASE_MAX_VARIANTS {
+            return None;
+        }
+        for j in 0..variants.len() {
+            let mut v = variants[j].clone();
+            v[i] ^= 0x20;
+            variants.push(v);
+        }
+    }
+
+    Some(variants.iter().map(|v| T::from_bytes(v)).collect())
+}
+
+// CharVariants returns the grams of every combination of the one-to-one
+// upper and lower case mappings of the chars in w, or None if there are
+// more than IGNORE_CASE_MAX_VARIANTS
+fn char_variants(w: &[char]) -> Option<Vec<T>> {
+    let mut variants = vec![w.to_vec()];
+
+    for i in 0..w.len() {
+        let mut alts = Vec::<char>::with_capacity(2);
+        let cases = [single(w[i].to_lowercase()), single(w[i].to_uppercase())];
+        for c in cases.iter().flatten() {
+            if *c != w[i] && !alts.contains(c) {
+                alts.push(*c);
+            }
+        }
+
+        let n = variants.len();
+        if n * (1 + alts.len()) > IGNORE_CASE_MAX_VARIANTS {
+            return None;
+        }
+        for c in alts {
+            for j in 0..n {
+                let mut v = variants[j].clone();
+                v[i] = c;
+                variants.push(v);
+            }
+        }
+    }
+
+    Some(variants.iter().map(|v| T::from_chars(v)).collect())
+}
+
+fn single(mut it: impl Iterator<Item = char>) -> Option<char> {
+    match (it.next(), it.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+impl Index {
+    // QueryIgnoreCase is like Query but matches letters regardless of case,
+    // even in an index built without case folding. Each query trigram is
+    // expanded into its case variants whose posting lists are unioned, and
+    // the unions are then intersected rarest first. Byte trigrams only vary
+    // ASCII letters.
+    pub fn query_ignore_case(&self, s: &str) -> Vec<DocID> {
+        let s = self.normalize(s);
+        let n = self.analyzer.gram_len();
+        // windows with too many variants are skipped
+        let windows: Vec<Vec<T>> = match self.analyzer.unit() {
+            Unit::Byte => s.as_bytes().windows(n).filter_map(byte_variants).collect(),
+            Unit::Char => {
+                let chars: Vec<char> = s.chars().collect();
+                chars.windows(n).filter_map(char_variants).collect()
+            }
+        };
+
+        let mut seen = Vec::<T>::new();
+        let mut unions = Vec::<(usize, Vec<&[DocID]>)>::new();
+
+        'window: for variants in windows.iter() {
+            let key = *variants.iter().min().unwrap();
+            if seen.contains(&key) {
+                continue;
+            }
+            seen.push(key);
+
+            let mut lists = Vec::<&[DocID]>::new();
+            for v in variants.iter() {
+                match self.postings.get(v) {
+                    None => continue,
+                    // some variant may be anywhere, so this position can't narrow
+                    Some(Posting::Pruned) => continue 'window,
+                    Some(Posting::List(l)) => lists.push(l),
+                }
+            }
+
+            if lists.is_empty() {
+                return Vec::new();
+            }
+
+            let size = lists.iter().map(|l| l.len()).sum();
+            unions.push((size, lists));
+        }
+
+        if unions.is_empty() {
+            return self.copy_all_docs();
+        }
+
+        unions.sort_by_key(|(size, _)| *size);
+        unions.truncate(IGNORE_CASE_MAX_TRIGRAMS);
+
+        let mut result = Vec::<DocID>::new();
+        for (i, (_, lists)) in unions.into_iter().enumerate() {
+            let docs: Vec<DocID> = CountMerge::new(lists).map(|(d, _)| d).collect();
+            if i == 0 {
+                result = docs;
+            } else {
+                intersect2(&mut result, &docs);
+            }
+            if result.is_empty() {
+                break;
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{byte_variants, char_variants};
+    use crate::{
+        extract_char_trigrams, extract_trigrams, CaseFold, DocID, Index, Options, Standard, Unit,
+    };
+
+    #[test]
+    fn test_case_fold() {
+        assert_eq!(CaseFold::Ascii.apply("FooBÄR"), "foobÄr");
+        assert_eq!(CaseFold::Unicode.apply("FooBÄR"), "foobär");
+        assert_eq!(CaseFold::Unicode.apply("ΟΔΟΣ οδος"), "οδοσ οδοσ");
+
+        let analyzer = Standard {
+            case_fold: CaseFold::Unicode,
+            ..Standard::default()
+        };
+        let mut idx = Index::with_analyzer(
+            analyzer,
+            Options {
+                store_documents: true,
+                ..Options::default()
+            },
+        );
+        idx.add("Straße");
+        idx.add("STRASSE");
+        idx.add("ÜberStraße");
+
+        assert_eq!(idx.query("straße"), vec![DocID(0), DocID(2)]);
+        assert_eq!(idx.query("STRAẞE"), vec![DocID(0), DocID(2)]);
+        assert_eq!(idx.query("Strasse"), vec![DocID(1)]);
+        assert_eq!(idx.query_fuzzy("übrstrasse", 3), vec![DocID(2)]);
+    }
+
+    #[test]
+    fn test_query_ignore_case() {
+        let docs = vec!["FooBar", "foobar", "FOOBAZ", "barfoo", "Foo"];
+        let idx = Index::new_with_documents(docs);
+
+        assert_eq!(idx.query("foobar"), vec![DocID(1)]);
+        assert_eq!(idx.query_ignore_case("foobar"), vec![DocID(0), DocID(1)]);
+        assert_eq!(
+            idx.query_ignore_case("FOO"),
+            vec![DocID(0), DocID(1), DocID(2), DocID(3), DocID(4)]
+        );
+        assert_eq!(
+            idx.query_ignore_case("oBa"),
+            vec![DocID(0), DocID(1), DocID(2)]
+        );
+        assert_eq!(idx.query_ignore_case("fooqux"), vec![]);
+
+        assert_eq!(byte_variants(b"a1b").map(|v| v.len()), Some(4));
+        assert_eq!(byte_variants(b"abcdefg"), None);
+        assert_eq!(char_variants(&['ä'; 7]), None);
+    }
+
+    #[test]
+    fn test_char_trigrams() {
+        let analyzer = Standard {
+            unit: Unit::Char,
+            ..Standard::default()
+        };
+        let mut idx = Index::with_analyzer(analyzer, Options::default());
+        idx.add("Привет мир");
+        idx.add("привет, Мир");
+        idx.add("日本語の文章");
+
+        let ts = extract_char_trigrams("Привет");
+        assert_eq!(ts.len(), 4);
+        assert_eq!(format!("{}", ts[0]), "T(При)");
+        assert_eq!(
+            format!("{:?}", extract_trigrams("Пр")),
+            "[T(\\xd0\\x9f\\xd1), T(\\x9f\\xd1\\x80)]"
+        );
+
+        assert_eq!(idx.query("ривет"), vec![DocID(0), DocID(1)]);
+        assert_eq!(idx.query("Мир"), vec![DocID(1)]);
+        assert_eq!(idx.query("本語"), vec![DocID(0), DocID(1), DocID(2)]);
+        assert_eq!(idx.query("本語の"), vec![DocID(2)]);
+        assert_eq!(idx.query_ignore_case("ПРИВЕТ МИР"), vec![DocID(0)]);
+        assert_eq!(idx.query_ignore_case("мир"), vec![DocID(0), DocID(1)]);
+    }
+}